@@ -0,0 +1,246 @@
+use crate::{
+    arg, block, expr_assign, expr_field, expr_method_call, expr_path, expr_reference,
+    generator::docs,
+    generics::{generic_path, impl_generics},
+    impl_item_fn, item_impl, self_mut, self_ref, signature, stmt_expr, stmt_semi, type_path,
+    type_reference, type_slice, visibility_public_variant, AttrsPropsBuilder, IntoExpr, IntoFnArg,
+    IntoImplItem, IntoType, IntoVisibility, ItemGenericsPropsBuilder, ItemImplBuilder,
+    MutabilityPropsBuilder, OutputPropsBuilder, VisPropsBuilder,
+};
+use proc_macro2::Ident;
+use syn::{
+    ext::IdentExt, Expr, Field, Fields, GenericArgument, ImplItem, ItemImpl, ItemStruct,
+    PathArguments, Signature, Stmt, Type, Visibility,
+};
+
+const ATTR: &str = "accessors";
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct AccessorOptions {
+    pub get: bool,
+    pub get_mut: bool,
+    pub set: bool,
+    pub copy: bool,
+}
+
+type Configure<'a> = Box<dyn FnMut(&Field, &mut AccessorOptions) + 'a>;
+
+pub struct Accessors<'a> {
+    item: ItemStruct,
+    vis: Visibility,
+    configure: Configure<'a>,
+}
+
+pub fn accessors<'a>(item: impl Into<ItemStruct>) -> Accessors<'a> {
+    Accessors {
+        item: item.into(),
+        vis: visibility_public_variant(),
+        configure: Box::new(|_, _| {}),
+    }
+}
+
+pub fn strip_accessor_attrs(item: impl Into<ItemStruct>) -> ItemStruct {
+    let mut item = item.into();
+    for field in &mut item.fields {
+        field.attrs.retain(|attr| !attr.path().is_ident(ATTR));
+    }
+    item
+}
+
+impl<'a> Accessors<'a> {
+    pub fn vis(self, vis: impl IntoVisibility) -> Self {
+        Self {
+            vis: vis.into_visibility(),
+            ..self
+        }
+    }
+
+    pub fn configure(self, configure: impl FnMut(&Field, &mut AccessorOptions) + 'a) -> Self {
+        Self {
+            configure: Box::new(configure),
+            ..self
+        }
+    }
+
+    pub fn build(mut self) -> syn::Result<ItemImpl> {
+        let Fields::Named(fields) = &self.item.fields else {
+            return Err(syn::Error::new(
+                self.item.ident.span(),
+                format!("`{}` needs named fields to get accessors", self.item.ident),
+            ));
+        };
+
+        let mut items = Vec::new();
+        for field in &fields.named {
+            let mut options = field_options(field)?;
+            (self.configure)(field, &mut options);
+            items.extend(self.field_accessors(field, options));
+        }
+
+        Ok(item_impl(type_path(generic_path(
+            &self.item.ident,
+            &self.item.generics,
+        )))
+        .generics(impl_generics(&self.item.generics))
+        .items(items))
+    }
+
+    fn field_accessors(&self, field: &Field, options: AccessorOptions) -> Vec<ImplItem> {
+        let ident = field.ident.as_ref().unwrap();
+        let name = ident.unraw().to_string();
+        let docs = docs(&field.attrs);
+        let field_expr = || expr_field(expr_path("self"), ident.clone());
+        let method = |sig: Signature, body: Stmt| {
+            impl_item_fn(sig, block([body]))
+                .vis(self.vis.clone())
+                .attrs(docs.clone())
+                .into_impl_item()
+        };
+
+        let mut items = Vec::new();
+        if options.get {
+            let (ty, body) = getter(&field.ty, field_expr().into_expr(), options.copy);
+            items.push(method(
+                signature(ident.clone(), [self_ref()]).output(ty),
+                stmt_expr(body),
+            ));
+        }
+        if options.get_mut {
+            items.push(method(
+                signature(format!("{name}_mut").as_str(), [self_mut()])
+                    .output(type_reference(field.ty.clone()).mutability(true)),
+                stmt_expr(expr_reference(field_expr()).mutability(true)),
+            ));
+        }
+        if options.set {
+            items.push(method(
+                signature(
+                    format!("set_{name}").as_str(),
+                    [
+                        self_mut().into_fn_arg(),
+                        arg("value", field.ty.clone()).into_fn_arg(),
+                    ],
+                ),
+                stmt_semi(expr_assign(field_expr(), expr_path("value"))),
+            ));
+        }
+        items
+    }
+}
+
+impl TryFrom<Accessors<'_>> for ItemImpl {
+    type Error = syn::Error;
+
+    fn try_from(accessors: Accessors<'_>) -> syn::Result<Self> {
+        accessors.build()
+    }
+}
+
+fn field_options(field: &Field) -> syn::Result<AccessorOptions> {
+    let mut options = AccessorOptions {
+        get: true,
+        get_mut: true,
+        set: true,
+        copy: is_copy_primitive(&field.ty),
+    };
+
+    for attr in field.attrs.iter().filter(|attr| attr.path().is_ident(ATTR)) {
+        let mut only = AccessorOptions {
+            get: false,
+            get_mut: false,
+            set: false,
+            copy: options.copy,
+        };
+        let mut restricted = false;
+        let mut skip = false;
+
+        attr.parse_nested_meta(|meta| {
+            let key = meta
+                .path
+                .get_ident()
+                .map(Ident::to_string)
+                .unwrap_or_default();
+            match key.as_str() {
+                "skip" => skip = true,
+                "get" => (restricted, only.get) = (true, true),
+                "get_mut" => (restricted, only.get_mut) = (true, true),
+                "set" => (restricted, only.set) = (true, true),
+                "copy" => only.copy = true,
+                "by_ref" => only.copy = false,
+                _ => {
+                    return Err(
+                        meta.error("expected `skip`, `get`, `get_mut`, `set`, `copy` or `by_ref`")
+                    )
+                }
+            }
+            Ok(())
+        })?;
+
+        options.copy = only.copy;
+        if skip {
+            (options.get, options.get_mut, options.set) = (false, false, false);
+        } else if restricted {
+            (options.get, options.get_mut, options.set) = (only.get, only.get_mut, only.set);
+        }
+    }
+
+    Ok(options)
+}
+
+fn getter(ty: &Type, field: Expr, copy: bool) -> (Type, Expr) {
+    if copy {
+        return (ty.clone(), field);
+    }
+
+    match last_segment(ty) {
+        Some((ident, _)) if ident == "String" => (
+            type_reference("str").into_type(),
+            expr_method_call(field, "as_str", Vec::<Expr>::new()).into_expr(),
+        ),
+        Some((ident, Some(elem))) if ident == "Vec" => (
+            type_reference(type_slice(elem.clone())).into_type(),
+            expr_method_call(field, "as_slice", Vec::<Expr>::new()).into_expr(),
+        ),
+        _ => (
+            type_reference(ty.clone()).into_type(),
+            expr_reference(field).into_expr(),
+        ),
+    }
+}
+
+fn last_segment(ty: &Type) -> Option<(&Ident, Option<&Type>)> {
+    let Type::Path(ty) = ty else {
+        return None;
+    };
+    if ty.qself.is_some() {
+        return None;
+    }
+
+    let segment = ty.path.segments.last()?;
+    let arg = match &segment.arguments {
+        PathArguments::AngleBracketed(args) if args.args.len() == 1 => match &args.args[0] {
+            GenericArgument::Type(ty) => Some(ty),
+            _ => None,
+        },
+        _ => None,
+    };
+    Some((&segment.ident, arg))
+}
+
+fn is_copy_primitive(ty: &Type) -> bool {
+    const PRIMITIVES: &[&str] = &[
+        "bool", "char", "i8", "i16", "i32", "i64", "i128", "isize", "u8", "u16", "u32", "u64",
+        "u128", "usize", "f32", "f64",
+    ];
+
+    match ty {
+        Type::Path(ty) => {
+            ty.qself.is_none()
+                && ty
+                    .path
+                    .get_ident()
+                    .is_some_and(|ident| PRIMITIVES.iter().any(|name| ident == name))
+        }
+        _ => false,
+    }
+}
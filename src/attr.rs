@@ -1,18 +1,82 @@
-use crate::{IntoExpr, IntoMacroDelimiter, IntoPath};
-use proc_macro2::TokenStream;
-use syn::{token, AttrStyle, Attribute, Meta, MetaList, MetaNameValue};
+use crate::{
+    expr_lit, lit_str, macro_delimiter_paren_variant,
+    span::{punctuated, push_punctuated, spanned},
+    IntoExpr, IntoMacroDelimiter, IntoPath,
+};
+use proc_macro2::{TokenStream, TokenTree};
+use quote::ToTokens;
+use syn::{
+    punctuated::Punctuated, token, AttrStyle, Attribute, Meta, MetaList, MetaNameValue, Path,
+};
 
 pub fn attribute(meta: impl IntoMeta) -> Attribute {
     Attribute {
-        pound_token: Default::default(),
+        pound_token: spanned(),
         style: AttrStyle::Outer,
-        bracket_token: Default::default(),
+        bracket_token: spanned(),
         meta: meta.into_meta(),
     }
 }
 
+pub fn attribute_doc(line: &str) -> Attribute {
+    let line = if line.is_empty() {
+        String::new()
+    } else {
+        format!(" {line}")
+    };
+
+    attribute(meta_name_value("doc", expr_lit(lit_str(&line))))
+}
+
+pub(crate) fn doc_lines(doc: &str) -> impl Iterator<Item = &str> {
+    doc.trim_end_matches(['\r', '\n'])
+        .split('\n')
+        .map(|line| line.trim_end_matches('\r'))
+}
+
+pub fn attribute_inner_doc(line: &str) -> Attribute {
+    Attribute {
+        style: attr_style_inner(),
+        ..attribute_doc(line)
+    }
+}
+
+pub fn attribute_derive<P: IntoPath>(paths: impl IntoIterator<Item = P>) -> Attribute {
+    attribute(meta_list(
+        "derive",
+        paths.into_iter().map(IntoPath::into_path),
+    ))
+}
+
+pub(crate) fn merge_derive(attrs: &mut Vec<Attribute>, paths: impl Iterator<Item = Path>) {
+    let existing = attrs.iter_mut().find_map(|attr| match &mut attr.meta {
+        Meta::List(list) if list.path.is_ident("derive") => list
+            .parse_args_with(Punctuated::<Path, token::Comma>::parse_terminated)
+            .ok()
+            .map(|derives| (list, derives)),
+        _ => None,
+    });
+
+    let Some((list, mut derives)) = existing else {
+        attrs.push(attribute_derive(paths));
+        return;
+    };
+
+    for path in paths {
+        let name = path.to_token_stream().to_string();
+        if !derives
+            .iter()
+            .any(|derive| derive.to_token_stream().to_string() == name)
+        {
+            push_punctuated(&mut derives, path);
+        }
+    }
+
+    list.tokens = derives.into_token_stream();
+}
+
 pub fn attr_style_inner() -> AttrStyle {
-    AttrStyle::Inner(Default::default())
+    AttrStyle::Inner(spanned())
 }
 
 pub trait AttributeBuilder {
@@ -27,7 +91,7 @@ impl AttributeBuilder for Attribute {
     fn style(self, inside: bool) -> Self {
         Self {
             style: if inside {
-                AttrStyle::Inner(Default::default())
+                AttrStyle::Inner(spanned())
             } else {
                 AttrStyle::Outer
             },
@@ -62,25 +126,60 @@ impl IntoMeta for Meta {
     }
 }
 
-pub fn meta_list(
-    path: impl IntoPath,
-    delimiter: impl IntoMacroDelimiter,
-    tokens: TokenStream,
-) -> MetaList {
+pub fn meta_list<M: IntoMeta>(path: impl IntoPath, args: impl IntoIterator<Item = M>) -> MetaList {
+    let args: Punctuated<Meta, token::Comma> =
+        punctuated(args.into_iter().map(IntoMeta::into_meta));
+
     MetaList {
         path: path.into_path(),
-        delimiter: delimiter.into_macro_delimiter(),
-        tokens,
+        delimiter: macro_delimiter_paren_variant(),
+        tokens: args.into_token_stream(),
     }
 }
 
 pub trait MetaListBuilder {
-    fn new(path: impl IntoPath, delimiter: impl IntoMacroDelimiter, tokens: TokenStream) -> Self;
+    fn new<M: IntoMeta>(path: impl IntoPath, args: impl IntoIterator<Item = M>) -> Self;
+    fn delimiter(self, delimiter: impl IntoMacroDelimiter) -> Self;
+    fn tokens(self, tokens: impl Into<TokenStream>) -> Self;
+    fn arg(self, arg: impl IntoMeta) -> Self;
+    fn args<M: IntoMeta>(self, args: impl IntoIterator<Item = M>) -> Self;
 }
 
 impl MetaListBuilder for MetaList {
-    fn new(path: impl IntoPath, delimiter: impl IntoMacroDelimiter, tokens: TokenStream) -> Self {
-        meta_list(path, delimiter, tokens)
+    fn new<M: IntoMeta>(path: impl IntoPath, args: impl IntoIterator<Item = M>) -> Self {
+        meta_list(path, args)
+    }
+
+    fn delimiter(self, delimiter: impl IntoMacroDelimiter) -> Self {
+        Self {
+            delimiter: delimiter.into_macro_delimiter(),
+            ..self
+        }
+    }
+
+    fn tokens(self, tokens: impl Into<TokenStream>) -> Self {
+        Self {
+            tokens: tokens.into(),
+            ..self
+        }
+    }
+
+    fn arg(mut self, arg: impl IntoMeta) -> Self {
+        let trailing_comma = matches!(
+            self.tokens.clone().into_iter().last(),
+            Some(TokenTree::Punct(punct)) if punct.as_char() == ','
+        );
+
+        if !self.tokens.is_empty() && !trailing_comma {
+            spanned::<token::Comma>().to_tokens(&mut self.tokens);
+        }
+
+        arg.into_meta().to_tokens(&mut self.tokens);
+        self
+    }
+
+    fn args<M: IntoMeta>(self, args: impl IntoIterator<Item = M>) -> Self {
+        args.into_iter().fold(self, Self::arg)
     }
 }
 
@@ -93,7 +192,7 @@ impl IntoMeta for MetaList {
 pub fn meta_name_value(path: impl IntoPath, value: impl IntoExpr) -> MetaNameValue {
     MetaNameValue {
         path: path.into_path(),
-        eq_token: Default::default(),
+        eq_token: spanned(),
         value: value.into_expr(),
     }
 }
@@ -0,0 +1,91 @@
+use crate::pretty_file;
+use std::{
+    env, io,
+    path::{Path, PathBuf},
+};
+use syn::File;
+
+#[derive(Clone)]
+pub struct BuildOutput {
+    name: String,
+    out_dir: Option<PathBuf>,
+    header: Option<String>,
+    inputs: Vec<PathBuf>,
+}
+
+pub fn build_output(name: &str) -> BuildOutput {
+    BuildOutput {
+        name: name.to_string(),
+        out_dir: None,
+        header: None,
+        inputs: Vec::new(),
+    }
+}
+
+impl BuildOutput {
+    pub fn out_dir(self, out_dir: impl AsRef<Path>) -> Self {
+        Self {
+            out_dir: Some(out_dir.as_ref().to_path_buf()),
+            ..self
+        }
+    }
+
+    pub fn header(self, header: &str) -> Self {
+        Self {
+            header: Some(header.to_string()),
+            ..self
+        }
+    }
+
+    pub fn input(mut self, input: impl AsRef<Path>) -> Self {
+        self.inputs.push(input.as_ref().to_path_buf());
+        self
+    }
+
+    pub fn inputs<P: AsRef<Path>>(self, inputs: impl IntoIterator<Item = P>) -> Self {
+        inputs.into_iter().fold(self, Self::input)
+    }
+
+    pub fn rerun_if_changed(&self) -> Vec<String> {
+        self.inputs
+            .iter()
+            .map(|input| format!("cargo:rerun-if-changed={}", input.display()))
+            .collect()
+    }
+
+    pub fn path(&self) -> io::Result<PathBuf> {
+        let out_dir = match &self.out_dir {
+            Some(out_dir) => out_dir.clone(),
+            None => env::var_os("OUT_DIR").map(PathBuf::from).ok_or_else(|| {
+                io::Error::new(
+                    io::ErrorKind::NotFound,
+                    "`OUT_DIR` is not set, `build_output` must be used from a build script",
+                )
+            })?,
+        };
+
+        let mut path = out_dir.join(&self.name);
+        if path.extension().is_none() {
+            path.set_extension("rs");
+        }
+
+        Ok(path)
+    }
+
+    pub fn write(&self, file: impl Into<File>) -> io::Result<PathBuf> {
+        let path = self.path()?;
+
+        for line in self.rerun_if_changed() {
+            println!("{line}");
+        }
+
+        let pretty = pretty_file(file);
+        let pretty = match &self.header {
+            Some(header) => pretty.header(header),
+            None => pretty,
+        };
+        pretty.write_file(&path)?;
+
+        Ok(path)
+    }
+}
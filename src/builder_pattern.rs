@@ -0,0 +1,281 @@
+use crate::{
+    angle_bracketed_generic_arguments, arg, block, expr_assign, expr_call, expr_closure,
+    expr_field, expr_lit, expr_method_call, expr_path, expr_struct, expr_try, field, field_value,
+    fields_named,
+    generator::{docs, error_struct, option, result},
+    generics::{generic_path, impl_generics},
+    impl_item_fn, item_impl, item_struct, path, path_segment, receiver, self_value, signature,
+    trait_bound, type_impl_trait, type_path, AttrsPropsBuilder, FieldBuilder, IntoExpr, IntoFnArg,
+    IntoIdent, IntoImplItem, IntoPath, IntoType, ItemGenericsPropsBuilder, ItemImplBuilder,
+    OutputPropsBuilder, PathSeqmentBuilder, ReceiverBuilder, VisPropsBuilder,
+};
+use proc_macro2::Ident;
+use syn::{Expr, Field, Fields, GenericArgument, Item, ItemStruct, Path, PathArguments, Type};
+
+#[derive(Clone)]
+pub struct BuilderPattern {
+    item: ItemStruct,
+    name: Option<Ident>,
+    defaults: Vec<(String, Expr)>,
+    into_setters: bool,
+    error: Path,
+    emit_error: bool,
+}
+
+pub fn builder_pattern(item: impl Into<ItemStruct>) -> BuilderPattern {
+    BuilderPattern {
+        item: item.into(),
+        name: None,
+        defaults: Vec::new(),
+        into_setters: false,
+        error: "MissingField".into_path(),
+        emit_error: false,
+    }
+}
+
+pub fn missing_field_error() -> Vec<Item> {
+    error_struct(
+        "MissingField",
+        "&'static str",
+        &["Debug", "Clone", "Copy", "PartialEq", "Eq"],
+        "missing field `{}`",
+    )
+}
+
+struct Setter<'a> {
+    field: &'a Field,
+    ident: &'a Ident,
+    optional: Option<&'a Type>,
+    default: Option<&'a Expr>,
+}
+
+impl BuilderPattern {
+    pub fn name(self, name: impl IntoIdent) -> Self {
+        Self {
+            name: Some(name.into_ident()),
+            ..self
+        }
+    }
+
+    pub fn default(mut self, field: &str, value: impl IntoExpr) -> Self {
+        self.defaults.push((field.to_string(), value.into_expr()));
+        self
+    }
+
+    pub fn into_setters(self, into_setters: bool) -> Self {
+        Self {
+            into_setters,
+            ..self
+        }
+    }
+
+    pub fn error(self, error: impl IntoPath) -> Self {
+        Self {
+            error: error.into_path(),
+            ..self
+        }
+    }
+
+    pub fn emit_error(self, emit_error: bool) -> Self {
+        Self { emit_error, ..self }
+    }
+
+    pub fn build(self) -> syn::Result<Vec<Item>> {
+        let item = &self.item;
+        let Fields::Named(_) = &item.fields else {
+            return Err(syn::Error::new(
+                item.ident.span(),
+                format!("`{}` needs named fields to get a builder", item.ident),
+            ));
+        };
+
+        let name = self
+            .name
+            .clone()
+            .unwrap_or_else(|| format!("{}Builder", item.ident).as_str().into_ident());
+        let setters = self.setters();
+        let impl_generics = impl_generics(&item.generics);
+        let target = type_path(generic_path(&item.ident, &item.generics));
+        let builder = type_path(generic_path(&name, &item.generics));
+
+        let builder_struct = item_struct(
+            name.clone(),
+            fields_named(setters.iter().map(|setter| {
+                let ty = match setter.optional {
+                    Some(_) => setter.field.ty.clone(),
+                    None => option(setter.field.ty.clone()),
+                };
+                field(ty).ident(setter.ident.clone())
+            })),
+        )
+        .vis(item.vis.clone())
+        .generics(item.generics.clone());
+
+        let constructor = item_impl(target.clone())
+            .generics(impl_generics.clone())
+            .item(
+                impl_item_fn(
+                    signature("builder", Vec::<syn::FnArg>::new()).output(builder.clone()),
+                    block([expr_struct(
+                        name.clone(),
+                        setters
+                            .iter()
+                            .map(|setter| field_value(setter.ident.clone(), expr_path("None"))),
+                    )
+                    .into_expr()]),
+                )
+                .vis(item.vis.clone()),
+            );
+
+        let methods = setters
+            .iter()
+            .map(|setter| self.setter(setter, &item.vis))
+            .chain([self.build_fn(&setters, &target, &item.vis)]);
+        let builder_impl = item_impl(builder)
+            .generics(impl_generics)
+            .items(methods.collect::<Vec<_>>());
+
+        let mut items = vec![
+            builder_struct.into(),
+            constructor.into(),
+            builder_impl.into(),
+        ];
+        if self.emit_error {
+            items.extend(missing_field_error());
+        }
+        Ok(items)
+    }
+
+    fn setters(&self) -> Vec<Setter<'_>> {
+        self.item
+            .fields
+            .iter()
+            .map(|field| {
+                let ident = field.ident.as_ref().unwrap();
+                let default = self
+                    .defaults
+                    .iter()
+                    .rev()
+                    .find(|(name, _)| ident == name)
+                    .map(|(_, value)| value);
+
+                Setter {
+                    field,
+                    ident,
+                    optional: option_inner(&field.ty),
+                    default,
+                }
+            })
+            .collect()
+    }
+
+    fn setter(&self, setter: &Setter, vis: &syn::Visibility) -> syn::ImplItem {
+        let ty = setter.optional.unwrap_or(&setter.field.ty).clone();
+        let (ty, value): (Type, Expr) = if self.into_setters {
+            let into = GenericArgument::Type(ty);
+            let bound = trait_bound(path([
+                path_segment("Into").arguments(angle_bracketed_generic_arguments([into]))
+            ]));
+            (
+                type_impl_trait([bound]).into_type(),
+                expr_method_call(expr_path("value"), "into", Vec::<Expr>::new()).into_expr(),
+            )
+        } else {
+            (ty, expr_path("value").into_expr())
+        };
+
+        impl_item_fn(
+            signature(
+                setter.ident.clone(),
+                [
+                    receiver().mutability(true).into_fn_arg(),
+                    arg("value", ty).into_fn_arg(),
+                ],
+            )
+            .output("Self"),
+            block([
+                expr_assign(
+                    expr_field(expr_path("self"), setter.ident.clone()),
+                    expr_call(expr_path("Some"), [value]),
+                )
+                .into_expr(),
+                expr_path("self").into_expr(),
+            ]),
+        )
+        .vis(vis.clone())
+        .attrs(docs(&setter.field.attrs))
+        .into_impl_item()
+    }
+
+    fn build_fn(
+        &self,
+        setters: &[Setter],
+        target: &syn::TypePath,
+        vis: &syn::Visibility,
+    ) -> syn::ImplItem {
+        let value = |setter: &Setter| -> Expr {
+            let field = expr_field(expr_path("self"), setter.ident.clone());
+            let (eager, lazy) = match setter.optional {
+                Some(_) => ("or", "or_else"),
+                None => ("unwrap_or", "unwrap_or_else"),
+            };
+            let wrap = |default: &Expr| match setter.optional {
+                Some(_) => expr_call(expr_path("Some"), [default.clone()]).into_expr(),
+                None => default.clone(),
+            };
+            match (setter.default, setter.optional) {
+                (Some(default @ (Expr::Lit(_) | Expr::Path(_))), _) => {
+                    expr_method_call(field, eager, [wrap(default)]).into_expr()
+                }
+                (Some(default), _) => expr_method_call(
+                    field,
+                    lazy,
+                    [expr_closure(Vec::<syn::Pat>::new(), wrap(default))],
+                )
+                .into_expr(),
+                (None, Some(_)) => field.into_expr(),
+                (None, None) => {
+                    let error = expr_call(
+                        expr_path(self.error.clone()),
+                        [expr_lit(setter.ident.to_string().as_str())],
+                    );
+                    expr_try(expr_method_call(field, "ok_or", [error])).into_expr()
+                }
+            }
+        };
+
+        let output = result(target.clone(), type_path(self.error.clone()));
+
+        impl_item_fn(
+            signature("build", [self_value()]).output(output),
+            block([expr_call(
+                expr_path("Ok"),
+                [expr_struct(
+                    self.item.ident.clone(),
+                    setters
+                        .iter()
+                        .map(|setter| field_value(setter.ident.clone(), value(setter))),
+                )],
+            )
+            .into_expr()]),
+        )
+        .vis(vis.clone())
+        .into_impl_item()
+    }
+}
+
+fn option_inner(ty: &Type) -> Option<&Type> {
+    let Type::Path(ty) = ty else {
+        return None;
+    };
+    let segment = ty.path.segments.last()?;
+    let PathArguments::AngleBracketed(args) = &segment.arguments else {
+        return None;
+    };
+    match args.args.first()? {
+        GenericArgument::Type(inner) if segment.ident == "Option" && args.args.len() == 1 => {
+            Some(inner)
+        }
+        _ => None,
+    }
+}
@@ -0,0 +1,38 @@
+use crate::{attribute, meta_list, meta_name_value, IntoMeta, IntoPath};
+use syn::{Attribute, MetaList, MetaNameValue};
+
+pub fn cfg_feature(name: &str) -> MetaNameValue {
+    cfg_key_value("feature", name)
+}
+
+pub fn cfg_key_value(key: impl IntoPath, value: &str) -> MetaNameValue {
+    meta_name_value(key, value)
+}
+
+pub fn cfg_all<P: IntoMeta>(predicates: impl IntoIterator<Item = P>) -> MetaList {
+    meta_list("all", predicates)
+}
+
+pub fn cfg_any<P: IntoMeta>(predicates: impl IntoIterator<Item = P>) -> MetaList {
+    meta_list("any", predicates)
+}
+
+pub fn cfg_not(predicate: impl IntoMeta) -> MetaList {
+    meta_list("not", [predicate])
+}
+
+pub fn attribute_cfg(predicate: impl IntoMeta) -> Attribute {
+    attribute(meta_list("cfg", [predicate]))
+}
+
+pub fn attribute_cfg_attr<A: IntoMeta>(
+    predicate: impl IntoMeta,
+    attrs: impl IntoIterator<Item = A>,
+) -> Attribute {
+    attribute(meta_list(
+        "cfg_attr",
+        [predicate.into_meta()]
+            .into_iter()
+            .chain(attrs.into_iter().map(IntoMeta::into_meta)),
+    ))
+}
@@ -1,6 +1,7 @@
 use crate::{
     attrs_builder,
     macros::{AttrsPropsBuilder, VisPropsBuilder},
+    span::{punctuated, spanned},
     vis_builder, IntoExpr, IntoIdent, IntoType,
 };
 use syn::{Field, FieldMutability, Fields, FieldsNamed, FieldsUnnamed, Variant, Visibility};
@@ -29,7 +30,7 @@ impl VariantBuilder for Variant {
 
     fn discriminant(self, expr: impl IntoExpr) -> Self {
         Self {
-            discriminant: Some((Default::default(), expr.into_expr())),
+            discriminant: Some((spanned(), expr.into_expr())),
             ..self
         }
     }
@@ -54,8 +55,8 @@ impl IntoFields for Fields {
 
 pub fn fields_named<F: Into<Field>>(fields: impl IntoIterator<Item = F>) -> FieldsNamed {
     FieldsNamed {
-        brace_token: Default::default(),
-        named: FromIterator::from_iter(fields.into_iter().map(Into::into)),
+        brace_token: spanned(),
+        named: punctuated(fields.into_iter().map(Into::into)),
     }
 }
 
@@ -77,8 +78,8 @@ impl IntoFields for FieldsNamed {
 
 pub fn fields_unamed<F: Into<Field>>(fields: impl IntoIterator<Item = F>) -> FieldsUnnamed {
     FieldsUnnamed {
-        paren_token: Default::default(),
-        unnamed: FromIterator::from_iter(fields.into_iter().map(Into::into)),
+        paren_token: spanned(),
+        unnamed: punctuated(fields.into_iter().map(Into::into)),
     }
 }
 
@@ -125,7 +126,7 @@ impl FieldBuilder for Field {
     fn ident(self, ident: impl IntoIdent) -> Self {
         Self {
             ident: Some(ident.into_ident()),
-            colon_token: Some(Default::default()),
+            colon_token: Some(spanned()),
             ..self
         }
     }
@@ -1,6 +1,7 @@
 use crate::{
-    attrs_builder,
-    macros::{AttrsPropsBuilder, VisPropsBuilder},
+    attrs_builder, derive_builder,
+    macros::{AttrsPropsBuilder, DerivePropsBuilder, VisPropsBuilder},
+    span::{punctuated, spanned},
     vis_builder, IntoFields, IntoIdent,
 };
 use syn::{
@@ -18,9 +19,10 @@ pub fn derive_input(ident: impl IntoIdent, data: impl IntoData) -> DeriveInput {
 }
 
 attrs_builder!(DeriveInput);
+derive_builder!(DeriveInput);
 vis_builder!(DeriveInput);
 
-pub trait DeriveInputBuilder: AttrsPropsBuilder + VisPropsBuilder {
+pub trait DeriveInputBuilder: AttrsPropsBuilder + DerivePropsBuilder + VisPropsBuilder {
     fn new(ident: impl IntoIdent, data: impl IntoData) -> Self;
     fn generics(self, generics: Generics) -> Self;
 }
@@ -47,7 +49,7 @@ impl IntoData for Data {
 
 pub fn data_struct(fields: impl IntoFields) -> DataStruct {
     DataStruct {
-        struct_token: Default::default(),
+        struct_token: spanned(),
         fields: fields.into_fields(),
         semi_token: None,
     }
@@ -71,9 +73,9 @@ impl IntoData for DataStruct {
 
 pub fn data_enum<V: Into<Variant>>(variants: impl IntoIterator<Item = V>) -> DataEnum {
     DataEnum {
-        enum_token: Default::default(),
-        brace_token: Default::default(),
-        variants: FromIterator::from_iter(variants.into_iter().map(Into::into)),
+        enum_token: spanned(),
+        brace_token: spanned(),
+        variants: punctuated(variants.into_iter().map(Into::into)),
     }
 }
 
@@ -95,7 +97,7 @@ impl IntoData for DataEnum {
 
 pub fn data_union(fields: impl Into<FieldsNamed>) -> DataUnion {
     DataUnion {
-        union_token: Default::default(),
+        union_token: spanned(),
         fields: fields.into(),
     }
 }
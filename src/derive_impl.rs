@@ -0,0 +1,700 @@
+use crate::{
+    arg, bin_op_and_variant, bin_op_eq_variant, block, expr_binary, expr_block, expr_call,
+    expr_closure, expr_field, expr_lit, expr_match, expr_method_call, expr_path, expr_reference,
+    expr_struct, expr_tuple, expr_unary, field_pat, field_value,
+    generator::fmt_signature,
+    generics,
+    generics::{generic_path, impl_generics},
+    impl_item_fn, item_impl, local, local_init, pat_ident, pat_path, pat_rest, pat_struct,
+    pat_tuple, pat_tuple_struct, pat_type, pat_wild, path, path_segment, predicate_type, self_ref,
+    signature,
+    span::push_punctuated,
+    stmt_semi, trait_bound, type_param, type_path, un_op_deref, where_clause, ExprMatchBuilder,
+    IntoExpr, IntoFnArg, IntoIdent, IntoImplItem, IntoPat, IntoPath, IntoStmt, IntoWherePredicate,
+    ItemGenericsPropsBuilder, ItemImplBuilder, LocalBuilder, OutputPropsBuilder, PatStructBuilder,
+    TypeParamBuilder,
+};
+use proc_macro2::Ident;
+use syn::{
+    Data, DeriveInput, Expr, Fields, GenericParam, ImplItem, ItemImpl, Member, Pat, Path,
+    Signature, Stmt, WherePredicate,
+};
+
+#[derive(Clone, Copy, PartialEq)]
+enum StdTrait {
+    Clone,
+    Debug,
+    PartialEq,
+    Eq,
+    Hash,
+    PartialOrd,
+    Default,
+}
+
+impl StdTrait {
+    fn path(self) -> &'static str {
+        match self {
+            StdTrait::Clone => "::core::clone::Clone",
+            StdTrait::Debug => "::core::fmt::Debug",
+            StdTrait::PartialEq => "::core::cmp::PartialEq",
+            StdTrait::Eq => "::core::cmp::Eq",
+            StdTrait::Hash => "::core::hash::Hash",
+            StdTrait::PartialOrd => "::core::cmp::PartialOrd",
+            StdTrait::Default => "::core::default::Default",
+        }
+    }
+}
+
+#[derive(Clone)]
+pub struct DeriveImpl {
+    input: DeriveInput,
+    trait_: StdTrait,
+    skip: Vec<String>,
+    with: Vec<(String, Path)>,
+    bounds: Option<Vec<WherePredicate>>,
+    default_variant: Option<Ident>,
+}
+
+fn derive_impl(input: impl Into<DeriveInput>, trait_: StdTrait) -> DeriveImpl {
+    DeriveImpl {
+        input: input.into(),
+        trait_,
+        skip: Vec::new(),
+        with: Vec::new(),
+        bounds: None,
+        default_variant: None,
+    }
+}
+
+pub fn derive_clone(input: impl Into<DeriveInput>) -> DeriveImpl {
+    derive_impl(input, StdTrait::Clone)
+}
+
+pub fn derive_debug(input: impl Into<DeriveInput>) -> DeriveImpl {
+    derive_impl(input, StdTrait::Debug)
+}
+
+pub fn derive_partial_eq(input: impl Into<DeriveInput>) -> DeriveImpl {
+    derive_impl(input, StdTrait::PartialEq)
+}
+
+pub fn derive_eq(input: impl Into<DeriveInput>) -> DeriveImpl {
+    derive_impl(input, StdTrait::Eq)
+}
+
+pub fn derive_hash(input: impl Into<DeriveInput>) -> DeriveImpl {
+    derive_impl(input, StdTrait::Hash)
+}
+
+pub fn derive_partial_ord(input: impl Into<DeriveInput>) -> DeriveImpl {
+    derive_impl(input, StdTrait::PartialOrd)
+}
+
+pub fn derive_default(input: impl Into<DeriveInput>) -> DeriveImpl {
+    derive_impl(input, StdTrait::Default)
+}
+
+struct Binding {
+    member: Member,
+    skip: bool,
+    with: Option<Path>,
+    this: Expr,
+    other: Expr,
+    this_value: Expr,
+    other_value: Expr,
+    this_pat: Pat,
+    other_pat: Pat,
+}
+
+impl Binding {
+    fn call(&self, default: &str, args: impl IntoIterator<Item = Expr>) -> Expr {
+        let func = match &self.with {
+            Some(with) => with.clone(),
+            None => default.into_path(),
+        };
+        expr_call(expr_path(func), args).into_expr()
+    }
+}
+
+struct Shape<'a> {
+    path: Path,
+    name: &'a Ident,
+    fields: &'a Fields,
+    bindings: Vec<Binding>,
+}
+
+impl Shape<'_> {
+    fn construct(&self, value: impl Fn(&Binding) -> Expr) -> Expr {
+        match self.fields {
+            Fields::Named(_) => expr_struct(
+                self.path.clone(),
+                self.bindings
+                    .iter()
+                    .map(|binding| field_value(binding.member.clone(), value(binding))),
+            )
+            .into_expr(),
+            Fields::Unnamed(_) => expr_call(
+                expr_path(self.path.clone()),
+                self.bindings.iter().map(value),
+            )
+            .into_expr(),
+            Fields::Unit => expr_path(self.path.clone()).into_expr(),
+        }
+    }
+
+    fn pat(&self, pat: impl Fn(&Binding) -> Pat) -> Pat {
+        match self.fields {
+            Fields::Named(_) => pat_struct(
+                self.path.clone(),
+                self.bindings
+                    .iter()
+                    .map(|binding| field_pat(binding.member.clone(), pat(binding))),
+            )
+            .into_pat(),
+            Fields::Unnamed(_) => {
+                pat_tuple_struct(self.path.clone(), self.bindings.iter().map(pat)).into_pat()
+            }
+            Fields::Unit => pat_path(self.path.clone()).into_pat(),
+        }
+    }
+
+    fn this_pat(&self) -> Pat {
+        self.pat(|binding| binding.this_pat.clone())
+    }
+
+    fn other_pat(&self) -> Pat {
+        self.pat(|binding| binding.other_pat.clone())
+    }
+
+    fn fields(&self) -> impl Iterator<Item = &Binding> {
+        self.bindings.iter().filter(|binding| !binding.skip)
+    }
+}
+
+impl DeriveImpl {
+    pub fn skip(mut self, field: &str) -> Self {
+        self.skip.push(field.to_string());
+        self
+    }
+
+    pub fn with(mut self, field: &str, func: impl IntoPath) -> Self {
+        self.with.push((field.to_string(), func.into_path()));
+        self
+    }
+
+    pub fn bounds<P: IntoWherePredicate>(self, predicates: impl IntoIterator<Item = P>) -> Self {
+        Self {
+            bounds: Some(
+                predicates
+                    .into_iter()
+                    .map(IntoWherePredicate::into_where_predicate)
+                    .collect(),
+            ),
+            ..self
+        }
+    }
+
+    pub fn default_variant(self, variant: impl IntoIdent) -> Self {
+        Self {
+            default_variant: Some(variant.into_ident()),
+            ..self
+        }
+    }
+
+    pub fn build(self) -> syn::Result<ItemImpl> {
+        if let Data::Union(data) = &self.input.data {
+            return Err(syn::Error::new_spanned(
+                data.union_token,
+                format!(
+                    "`{}` is a union, which std trait impls do not support",
+                    self.input.ident
+                ),
+            ));
+        }
+
+        let mut generics = impl_generics(&self.input.generics);
+        let bounds = self.bounds.clone().unwrap_or_else(|| {
+            self.input
+                .generics
+                .params
+                .iter()
+                .filter_map(|param| match param {
+                    GenericParam::Type(param) => Some(
+                        predicate_type(
+                            type_path(param.ident.clone()),
+                            [trait_bound(self.trait_.path())],
+                        )
+                        .into_where_predicate(),
+                    ),
+                    _ => None,
+                })
+                .collect()
+        });
+        if !bounds.is_empty() {
+            let where_clause = generics
+                .where_clause
+                .get_or_insert_with(|| where_clause(Vec::<WherePredicate>::new()));
+            for bound in bounds {
+                push_punctuated(&mut where_clause.predicates, bound);
+            }
+        }
+
+        let self_ty = type_path(generic_path(&self.input.ident, &self.input.generics));
+        let items: Vec<ImplItem> = self.impl_fn()?.into_iter().collect();
+
+        Ok(item_impl(self_ty)
+            .generics(generics)
+            .trait_(false, self.trait_.path())
+            .items(items))
+    }
+
+    fn shapes(&self) -> Vec<Shape<'_>> {
+        match &self.input.data {
+            Data::Struct(data) => {
+                vec![self.shape(
+                    path([path_segment("Self")]),
+                    &self.input.ident,
+                    &data.fields,
+                    false,
+                )]
+            }
+            Data::Enum(data) => data
+                .variants
+                .iter()
+                .map(|variant| {
+                    let path = path([path_segment("Self"), path_segment(variant.ident.clone())]);
+                    self.shape(path, &variant.ident, &variant.fields, true)
+                })
+                .collect(),
+            Data::Union(_) => Vec::new(),
+        }
+    }
+
+    fn shape<'a>(&self, path: Path, name: &'a Ident, fields: &'a Fields, bound: bool) -> Shape<'a> {
+        let bindings = fields
+            .members()
+            .enumerate()
+            .map(|(i, member)| {
+                let key = match &member {
+                    Member::Named(ident) => ident.to_string(),
+                    Member::Unnamed(index) => index.index.to_string(),
+                };
+                let skip = self.skip.contains(&key);
+                let with = self
+                    .with
+                    .iter()
+                    .rev()
+                    .find(|(field, _)| *field == key)
+                    .map(|(_, func)| func.clone());
+
+                let access = |side: &str| -> (Expr, Expr, Pat) {
+                    let ident = format!("__{side}_{i}");
+                    let pat = if skip {
+                        pat_wild().into_pat()
+                    } else {
+                        pat_ident(ident.as_str()).into_pat()
+                    };
+                    if bound {
+                        let binding = expr_path(ident.as_str()).into_expr();
+                        (binding.clone(), binding, pat)
+                    } else {
+                        let field = expr_field(expr_path(side), member.clone());
+                        (
+                            expr_reference(field.clone()).into_expr(),
+                            field.into_expr(),
+                            pat,
+                        )
+                    }
+                };
+                let (this, this_value, this_pat) = access("self");
+                let (other, other_value, other_pat) = access("other");
+
+                Binding {
+                    member,
+                    skip,
+                    with,
+                    this,
+                    other,
+                    this_value,
+                    other_value,
+                    this_pat,
+                    other_pat,
+                }
+            })
+            .collect();
+
+        Shape {
+            path,
+            name,
+            fields,
+            bindings,
+        }
+    }
+
+    fn is_enum(&self) -> bool {
+        matches!(self.input.data, Data::Enum(_))
+    }
+
+    fn impl_fn(&self) -> syn::Result<Option<ImplItem>> {
+        let (sig, body) = match self.trait_ {
+            StdTrait::Clone => (
+                signature("clone", [self_ref()]).output("Self"),
+                self.clone_body(),
+            ),
+            StdTrait::Debug => (fmt_signature("f"), self.debug_body()),
+            StdTrait::PartialEq => (self.comparison_sig("eq", "bool"), self.partial_eq_body()),
+            StdTrait::Eq => return Ok(None),
+            StdTrait::Hash => (
+                signature(
+                    "hash",
+                    [
+                        self_ref().into_fn_arg(),
+                        arg(self.param("state", 2), "&mut __H").into_fn_arg(),
+                    ],
+                )
+                .generics(generics([
+                    type_param("__H").bounds([trait_bound("::core::hash::Hasher")])
+                ])),
+                self.hash_body(),
+            ),
+            StdTrait::PartialOrd => (
+                self.comparison_sig(
+                    "partial_cmp",
+                    "::core::option::Option<::core::cmp::Ordering>",
+                ),
+                self.partial_ord_body(),
+            ),
+            StdTrait::Default => (
+                signature("default", Vec::<syn::FnArg>::new()).output("Self"),
+                self.default_body()?,
+            ),
+        };
+
+        Ok(Some(impl_item_fn(sig, block(body)).into_impl_item()))
+    }
+
+    fn param(&self, name: &str, matched_variants: usize) -> Ident {
+        let shapes = self.shapes();
+        let used = (self.is_enum() && shapes.len() >= matched_variants)
+            || shapes.iter().any(|shape| shape.fields().next().is_some());
+        if used {
+            name.into_ident()
+        } else {
+            format!("_{name}").as_str().into_ident()
+        }
+    }
+
+    fn comparison_sig(&self, name: &str, output: &str) -> Signature {
+        signature(
+            name,
+            [
+                self_ref().into_fn_arg(),
+                arg(self.param("other", 1), "&Self").into_fn_arg(),
+            ],
+        )
+        .output(output)
+    }
+
+    fn match_self(&self, arm: impl Fn(&Shape) -> Expr) -> Expr {
+        let shapes = self.shapes();
+        if !self.is_enum() {
+            return arm(&shapes[0]);
+        }
+
+        if shapes.is_empty() {
+            return expr_match(expr_unary(un_op_deref(), expr_path("self"))).into_expr();
+        }
+
+        expr_match(expr_path("self"))
+            .arms(shapes.iter().map(|shape| (shape.this_pat(), arm(shape))))
+            .into_expr()
+    }
+
+    fn match_pair(&self, arm: impl Fn(&Shape) -> Expr, fallback: Expr) -> Expr {
+        let shapes = self.shapes();
+        if !self.is_enum() {
+            return arm(&shapes[0]);
+        }
+        if shapes.is_empty() {
+            return expr_match(expr_unary(un_op_deref(), expr_path("self"))).into_expr();
+        }
+
+        let mut match_ = expr_match(expr_tuple([expr_path("self"), expr_path("other")])).arms(
+            shapes.iter().map(|shape| {
+                (
+                    pat_tuple([shape.this_pat(), shape.other_pat()]).into_pat(),
+                    arm(shape),
+                )
+            }),
+        );
+        if shapes.len() > 1 {
+            match_ = match_.wildcard(fallback);
+        }
+        match_.into_expr()
+    }
+
+    fn clone_body(&self) -> Vec<Stmt> {
+        vec![self
+            .match_self(|shape| {
+                shape.construct(|binding| {
+                    if binding.skip {
+                        expr_call(
+                            expr_path("::core::default::Default::default"),
+                            Vec::<Expr>::new(),
+                        )
+                        .into_expr()
+                    } else {
+                        binding.call("::core::clone::Clone::clone", [binding.this.clone()])
+                    }
+                })
+            })
+            .into_stmt()]
+    }
+
+    fn debug_body(&self) -> Vec<Stmt> {
+        vec![self
+            .match_self(|shape| {
+                let name = shape.name.to_string();
+                let formatter = expr_path("f");
+                let value = |binding: &Binding| match &binding.with {
+                    Some(with) => {
+                        expr_reference(expr_call(expr_path(with.clone()), [binding.this.clone()]))
+                            .into_expr()
+                    }
+                    None => binding.this.clone(),
+                };
+
+                let (builder, finish) = match shape.fields {
+                    Fields::Named(_) => {
+                        let builder = shape.fields().fold(
+                            expr_method_call(formatter, "debug_struct", [expr_lit(name.as_str())]),
+                            |builder, binding| {
+                                let Member::Named(ident) = &binding.member else {
+                                    unreachable!()
+                                };
+                                expr_method_call(
+                                    builder,
+                                    "field",
+                                    [
+                                        expr_lit(ident.to_string().as_str()).into_expr(),
+                                        value(binding),
+                                    ],
+                                )
+                            },
+                        );
+                        let skipped = shape.bindings.iter().any(|binding| binding.skip);
+                        (
+                            builder,
+                            if skipped {
+                                "finish_non_exhaustive"
+                            } else {
+                                "finish"
+                            },
+                        )
+                    }
+                    Fields::Unnamed(_) => {
+                        let builder = shape.fields().fold(
+                            expr_method_call(formatter, "debug_tuple", [expr_lit(name.as_str())]),
+                            |builder, binding| expr_method_call(builder, "field", [value(binding)]),
+                        );
+                        (builder, "finish")
+                    }
+                    Fields::Unit => {
+                        return expr_method_call(formatter, "write_str", [expr_lit(name.as_str())])
+                            .into_expr()
+                    }
+                };
+
+                expr_method_call(builder, finish, Vec::<Expr>::new()).into_expr()
+            })
+            .into_stmt()]
+    }
+
+    fn partial_eq_body(&self) -> Vec<Stmt> {
+        let body = self.match_pair(
+            |shape| {
+                shape
+                    .fields()
+                    .map(|binding| match &binding.with {
+                        Some(with) => expr_call(
+                            expr_path(with.clone()),
+                            [binding.this.clone(), binding.other.clone()],
+                        )
+                        .into_expr(),
+                        None => expr_binary(
+                            binding.this_value.clone(),
+                            bin_op_eq_variant(),
+                            binding.other_value.clone(),
+                        )
+                        .into_expr(),
+                    })
+                    .reduce(|all, eq| expr_binary(all, bin_op_and_variant(), eq).into_expr())
+                    .unwrap_or_else(|| expr_lit(true).into_expr())
+            },
+            expr_lit(false).into_expr(),
+        );
+
+        vec![body.into_stmt()]
+    }
+
+    fn hash_body(&self) -> Vec<Stmt> {
+        let hash = |shape: &Shape| -> Vec<Stmt> {
+            shape
+                .fields()
+                .map(|binding| {
+                    stmt_semi(binding.call(
+                        "::core::hash::Hash::hash",
+                        [binding.this.clone(), expr_path("state").into_expr()],
+                    ))
+                })
+                .collect()
+        };
+
+        let shapes = self.shapes();
+        if !self.is_enum() {
+            return hash(&shapes[0]);
+        }
+
+        let mut stmts = Vec::new();
+        if shapes.len() > 1 {
+            let discriminant = expr_call(
+                expr_path("::core::mem::discriminant"),
+                [expr_path("self").into_expr()],
+            );
+            stmts.push(stmt_semi(expr_call(
+                expr_path("::core::hash::Hash::hash"),
+                [
+                    expr_reference(discriminant).into_expr(),
+                    expr_path("state").into_expr(),
+                ],
+            )));
+        }
+        stmts.push(
+            self.match_self(|shape| expr_block(block(hash(shape))).into_expr())
+                .into_stmt(),
+        );
+        stmts
+    }
+
+    fn partial_ord_body(&self) -> Vec<Stmt> {
+        let equal = expr_call(
+            expr_path("::core::option::Option::Some"),
+            [expr_path("::core::cmp::Ordering::Equal")],
+        )
+        .into_expr();
+
+        let chain = |shape: &Shape| -> Expr {
+            let mut fields = shape.fields().collect::<Vec<_>>().into_iter().rev();
+            let Some(last) = fields.next() else {
+                return equal.clone();
+            };
+            let compare = |binding: &Binding| {
+                binding.call(
+                    "::core::cmp::PartialOrd::partial_cmp",
+                    [binding.this.clone(), binding.other.clone()],
+                )
+            };
+
+            fields.fold(compare(last), |rest, binding| {
+                expr_match(compare(binding))
+                    .arm(
+                        pat_tuple_struct(
+                            "::core::option::Option::Some",
+                            [pat_path("::core::cmp::Ordering::Equal")],
+                        ),
+                        rest,
+                    )
+                    .arm(pat_ident("cmp"), expr_path("cmp"))
+                    .into_expr()
+            })
+        };
+
+        let shapes = self.shapes();
+        if shapes.len() <= 1 {
+            return vec![self.match_pair(chain, equal.clone()).into_stmt()];
+        }
+
+        let index =
+            expr_match(expr_path("value")).arms(shapes.iter().enumerate().map(|(i, shape)| {
+                (
+                    pat_struct(shape.path.clone(), Vec::<syn::FieldPat>::new()).rest(pat_rest()),
+                    expr_lit(i),
+                )
+            }));
+        let fallback = expr_call(
+            expr_path("::core::cmp::PartialOrd::partial_cmp"),
+            [
+                expr_reference(expr_call(expr_path("__index"), [expr_path("self")])).into_expr(),
+                expr_reference(expr_call(expr_path("__index"), [expr_path("other")])).into_expr(),
+            ],
+        )
+        .into_expr();
+
+        vec![
+            local(pat_ident("__index"))
+                .init(local_init(expr_closure(
+                    [pat_type(pat_ident("value"), "&Self")],
+                    index,
+                )))
+                .into_stmt(),
+            self.match_pair(chain, fallback).into_stmt(),
+        ]
+    }
+
+    fn default_body(&self) -> syn::Result<Vec<Stmt>> {
+        let shapes = self.shapes();
+        let shape = match &self.input.data {
+            Data::Enum(data) => {
+                let variant = match &self.default_variant {
+                    Some(ident) => {
+                        let position = data
+                            .variants
+                            .iter()
+                            .position(|variant| variant.ident == *ident);
+                        position.ok_or_else(|| {
+                            syn::Error::new(
+                                ident.span(),
+                                format!("`{}` has no variant `{ident}`", self.input.ident),
+                            )
+                        })?
+                    }
+                    None => data
+                        .variants
+                        .iter()
+                        .position(|variant| {
+                            variant
+                                .attrs
+                                .iter()
+                                .any(|attr| attr.path().is_ident("default"))
+                        })
+                        .or((!data.variants.is_empty()).then_some(0))
+                        .ok_or_else(|| {
+                            syn::Error::new(
+                                self.input.ident.span(),
+                                format!(
+                                    "`{}` has no variant to use as the default",
+                                    self.input.ident
+                                ),
+                            )
+                        })?,
+                };
+                &shapes[variant]
+            }
+            _ => &shapes[0],
+        };
+
+        Ok(vec![shape
+            .construct(|binding| {
+                binding.call("::core::default::Default::default", Vec::<Expr>::new())
+            })
+            .into_stmt()])
+    }
+}
+
+impl TryFrom<DeriveImpl> for ItemImpl {
+    type Error = syn::Error;
+
+    fn try_from(derive_impl: DeriveImpl) -> syn::Result<Self> {
+        derive_impl.build()
+    }
+}
@@ -0,0 +1,184 @@
+use crate::{
+    bin_op_add_variant, bin_op_and_variant, bin_op_bit_and_variant, bin_op_bit_or_variant,
+    bin_op_bit_xor_variant, bin_op_div_variant, bin_op_eq_variant, bin_op_ge_variant,
+    bin_op_gt_variant, bin_op_le_variant, bin_op_lt_variant, bin_op_mul_variant, bin_op_ne_variant,
+    bin_op_or_variant, bin_op_rem_variant, bin_op_shl_variant, bin_op_shr_variant,
+    bin_op_sub_variant, expr_await, expr_binary, expr_call, expr_cast, expr_field, expr_index,
+    expr_method_call, expr_reference, expr_try, expr_unary, index, macros::MutabilityPropsBuilder,
+    precedence::parenthesize_operands, un_op_deref, un_op_neg, un_op_not, IntoBinOp, IntoExpr,
+    IntoIdent, IntoType,
+};
+use proc_macro2::TokenStream;
+use quote::ToTokens;
+use std::{fmt, ops};
+use syn::{Expr, Member};
+
+#[derive(Clone)]
+pub struct E(pub Expr);
+
+pub fn e(expr: impl IntoExpr) -> E {
+    E(expr.into_expr())
+}
+
+fn composed(expr: impl IntoExpr) -> E {
+    let mut expr = expr.into_expr();
+    parenthesize_operands(&mut expr);
+    E(expr)
+}
+
+impl IntoExpr for E {
+    fn into_expr(self) -> Expr {
+        self.0
+    }
+}
+
+impl ToTokens for E {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        self.0.to_tokens(tokens);
+    }
+}
+
+impl fmt::Debug for E {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("E")
+            .field(&format_args!("{}", self.0.to_token_stream()))
+            .finish()
+    }
+}
+
+impl From<Expr> for E {
+    fn from(expr: Expr) -> Self {
+        E(expr)
+    }
+}
+
+impl From<E> for Expr {
+    fn from(expr: E) -> Self {
+        expr.0
+    }
+}
+
+impl E {
+    fn binary(self, op: impl IntoBinOp, rhs: impl IntoExpr) -> E {
+        composed(expr_binary(self, op, rhs))
+    }
+
+    pub fn eq(self, rhs: impl IntoExpr) -> E {
+        self.binary(bin_op_eq_variant(), rhs)
+    }
+
+    pub fn ne(self, rhs: impl IntoExpr) -> E {
+        self.binary(bin_op_ne_variant(), rhs)
+    }
+
+    pub fn lt(self, rhs: impl IntoExpr) -> E {
+        self.binary(bin_op_lt_variant(), rhs)
+    }
+
+    pub fn le(self, rhs: impl IntoExpr) -> E {
+        self.binary(bin_op_le_variant(), rhs)
+    }
+
+    pub fn gt(self, rhs: impl IntoExpr) -> E {
+        self.binary(bin_op_gt_variant(), rhs)
+    }
+
+    pub fn ge(self, rhs: impl IntoExpr) -> E {
+        self.binary(bin_op_ge_variant(), rhs)
+    }
+
+    pub fn and(self, rhs: impl IntoExpr) -> E {
+        self.binary(bin_op_and_variant(), rhs)
+    }
+
+    pub fn or(self, rhs: impl IntoExpr) -> E {
+        self.binary(bin_op_or_variant(), rhs)
+    }
+
+    pub fn field(self, name: impl IntoIdent) -> E {
+        composed(expr_field(self, Member::Named(name.into_ident())))
+    }
+
+    pub fn tuple_field(self, i: usize) -> E {
+        composed(expr_field(self, index(i)))
+    }
+
+    pub fn call<A: IntoExpr>(self, args: impl IntoIterator<Item = A>) -> E {
+        composed(expr_call(self, args))
+    }
+
+    pub fn method<A: IntoExpr>(self, name: impl IntoIdent, args: impl IntoIterator<Item = A>) -> E {
+        composed(expr_method_call(self, name, args))
+    }
+
+    pub fn index(self, index: impl IntoExpr) -> E {
+        composed(expr_index(self, index))
+    }
+
+    pub fn await_(self) -> E {
+        composed(expr_await(self))
+    }
+
+    pub fn try_(self) -> E {
+        composed(expr_try(self))
+    }
+
+    pub fn cast(self, ty: impl IntoType) -> E {
+        composed(expr_cast(self, ty))
+    }
+
+    pub fn ref_(self) -> E {
+        composed(expr_reference(self))
+    }
+
+    pub fn ref_mut(self) -> E {
+        composed(expr_reference(self).mutability(true))
+    }
+
+    pub fn deref(self) -> E {
+        composed(expr_unary(un_op_deref(), self))
+    }
+}
+
+macro_rules! impl_bin_op_dsl {
+    ($($trait:ident::$method:ident => $op:ident,)*) => {
+        $(
+            impl<R: IntoExpr> ops::$trait<R> for E {
+                type Output = E;
+
+                fn $method(self, rhs: R) -> E {
+                    self.binary($op(), rhs)
+                }
+            }
+        )*
+    };
+}
+
+impl_bin_op_dsl!(
+    Add::add => bin_op_add_variant,
+    Sub::sub => bin_op_sub_variant,
+    Mul::mul => bin_op_mul_variant,
+    Div::div => bin_op_div_variant,
+    Rem::rem => bin_op_rem_variant,
+    BitAnd::bitand => bin_op_bit_and_variant,
+    BitOr::bitor => bin_op_bit_or_variant,
+    BitXor::bitxor => bin_op_bit_xor_variant,
+    Shl::shl => bin_op_shl_variant,
+    Shr::shr => bin_op_shr_variant,
+);
+
+impl ops::Neg for E {
+    type Output = E;
+
+    fn neg(self) -> E {
+        composed(expr_unary(un_op_neg(), self))
+    }
+}
+
+impl ops::Not for E {
+    type Output = E;
+
+    fn not(self) -> E {
+        composed(expr_unary(un_op_not(), self))
+    }
+}
@@ -0,0 +1,396 @@
+use crate::{
+    angle_bracketed_generic_arguments, arg, bin_op_eq_variant, block, expr_array, expr_binary,
+    expr_call, expr_cast, expr_lit, expr_match, expr_method_call, expr_path, expr_reference,
+    expr_tuple, expr_unary, field_pat,
+    generator::{error_struct, fmt_signature, option, result},
+    generics::{generic_path, impl_generics},
+    ident::{screaming_snake_case, snake_case, upper_camel_case},
+    ident_snake_case, impl_item_const, impl_item_fn, impl_item_type, item_impl, pat_ident, pat_lit,
+    pat_path, pat_rest, pat_struct, pat_tuple_struct, pat_wild, path, path_segment, self_ref,
+    self_value, signature, type_path, type_reference, type_slice, type_tuple, un_op_deref,
+    ExprMatchBuilder, IntoExpr, IntoIdent, IntoImplItem, IntoPat, IntoPath, IntoType,
+    ItemGenericsPropsBuilder, ItemImplBuilder, OutputPropsBuilder, PatStructBuilder, PathBuilder,
+    PathSeqmentBuilder, VisPropsBuilder,
+};
+use proc_macro2::Ident;
+use syn::{
+    ext::IdentExt, Expr, ExprMatch, FieldPat, Fields, ImplItem, Item, ItemEnum, ItemImpl, Lit, Pat,
+    Path, Type, Variant,
+};
+
+const INT_REPRS: &[&str] = &[
+    "u8", "u16", "u32", "u64", "u128", "usize", "i8", "i16", "i32", "i64", "i128", "isize",
+];
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Casing {
+    #[default]
+    Original,
+    Snake,
+    UpperCamel,
+    ScreamingSnake,
+}
+
+impl Casing {
+    fn apply(self, name: &str) -> String {
+        match self {
+            Casing::Original => name.to_string(),
+            Casing::Snake => snake_case(name),
+            Casing::UpperCamel => upper_camel_case(name),
+            Casing::ScreamingSnake => screaming_snake_case(name),
+        }
+    }
+}
+
+#[derive(Clone)]
+pub struct EnumUtils {
+    item: ItemEnum,
+    casing: Casing,
+    error: Path,
+}
+
+pub fn enum_utils(item: impl Into<ItemEnum>) -> EnumUtils {
+    EnumUtils {
+        item: item.into(),
+        casing: Casing::Original,
+        error: "UnknownVariant".into_path(),
+    }
+}
+
+pub fn unknown_variant_error() -> Vec<Item> {
+    error_struct(
+        "UnknownVariant",
+        "String",
+        &["Debug", "Clone", "PartialEq", "Eq"],
+        "unknown variant `{}`",
+    )
+}
+
+impl EnumUtils {
+    pub fn casing(self, casing: Casing) -> Self {
+        Self { casing, ..self }
+    }
+
+    pub fn error(self, error: impl IntoPath) -> Self {
+        Self {
+            error: error.into_path(),
+            ..self
+        }
+    }
+
+    pub fn build(self) -> syn::Result<Vec<Item>> {
+        self.check_method_names()?;
+
+        let mut items: Vec<ImplItem> = Vec::new();
+        for variant in &self.item.variants {
+            items.push(self.predicate_fn(variant));
+            if !variant.fields.is_empty() {
+                items.push(self.borrow_fn(variant));
+                items.push(self.take_fn(variant));
+            }
+        }
+        items.push(self.variant_name_fn());
+
+        let unit_only = self.unit_only();
+        if unit_only {
+            let all = expr_reference(expr_array(
+                self.item
+                    .variants
+                    .iter()
+                    .map(|variant| expr_path(variant_path(variant))),
+            ));
+            items.insert(
+                0,
+                impl_item_const("ALL", type_reference(type_slice("Self")), all)
+                    .vis(self.item.vis.clone())
+                    .into_impl_item(),
+            );
+        }
+
+        let mut impls = vec![self.item_impl().items(items)];
+        if unit_only {
+            impls.push(self.display_impl());
+            impls.push(self.parse_impl());
+            if let Some(repr) = self.int_repr() {
+                impls.push(self.try_from_impl(repr));
+            }
+        }
+
+        Ok(impls.into_iter().map(Item::Impl).collect())
+    }
+
+    fn check_method_names(&self) -> syn::Result<()> {
+        let mut seen: Vec<(Ident, &Variant)> = Vec::new();
+        for variant in &self.item.variants {
+            let name = method_name("is", variant);
+            if let Some((_, other)) = seen.iter().find(|(seen, _)| *seen == name) {
+                return Err(syn::Error::new(
+                    variant.ident.span(),
+                    format!(
+                        "variants `{}` and `{}` both get `{name}`",
+                        other.ident, variant.ident
+                    ),
+                ));
+            }
+            seen.push((name, variant));
+        }
+        Ok(())
+    }
+
+    fn item_impl(&self) -> ItemImpl {
+        item_impl(type_path(generic_path(
+            &self.item.ident,
+            &self.item.generics,
+        )))
+        .generics(impl_generics(&self.item.generics))
+    }
+
+    fn unit_only(&self) -> bool {
+        self.item
+            .variants
+            .iter()
+            .all(|variant| matches!(variant.fields, Fields::Unit))
+    }
+
+    fn int_repr(&self) -> Option<Ident> {
+        let mut repr = None;
+        for attr in self
+            .item
+            .attrs
+            .iter()
+            .filter(|attr| attr.path().is_ident("repr"))
+        {
+            let _ = attr.parse_nested_meta(|meta| {
+                if let Some(ident) = meta.path.get_ident() {
+                    if INT_REPRS.iter().any(|int| ident == int) {
+                        repr = Some(ident.clone());
+                    }
+                }
+                Ok(())
+            });
+        }
+        repr
+    }
+
+    fn predicate_fn(&self, variant: &Variant) -> ImplItem {
+        let mut matches = expr_match(expr_path("self")).arm(variant_pat(variant), expr_lit(true));
+        if self.item.variants.len() > 1 {
+            matches = matches.wildcard(expr_lit(false));
+        }
+
+        impl_item_fn(
+            signature(method_name("is", variant), [self_ref()]).output("bool"),
+            block([matches.into_expr()]),
+        )
+        .vis(self.item.vis.clone())
+        .into_impl_item()
+    }
+
+    fn borrow_fn(&self, variant: &Variant) -> ImplItem {
+        let output = option(fields_type(variant, |ty| type_reference(ty).into_type()));
+        impl_item_fn(
+            signature(method_name("as", variant), [self_ref()]).output(output),
+            block([self.extract(variant)]),
+        )
+        .vis(self.item.vis.clone())
+        .into_impl_item()
+    }
+
+    fn take_fn(&self, variant: &Variant) -> ImplItem {
+        let output = option(fields_type(variant, |ty| ty));
+        impl_item_fn(
+            signature(method_name("into", variant), [self_value()]).output(output),
+            block([self.extract(variant)]),
+        )
+        .vis(self.item.vis.clone())
+        .into_impl_item()
+    }
+
+    fn extract(&self, variant: &Variant) -> Expr {
+        let bindings: Vec<Ident> = variant
+            .fields
+            .iter()
+            .enumerate()
+            .map(|(i, field)| match &field.ident {
+                Some(ident) => ident.clone(),
+                None => format!("field_{i}").as_str().into_ident(),
+            })
+            .collect();
+
+        let pat: Pat = match &variant.fields {
+            Fields::Named(_) => pat_struct(
+                variant_path(variant),
+                bindings.iter().map(|binding| FieldPat {
+                    colon_token: None,
+                    ..field_pat(binding.clone(), pat_ident(binding.clone()))
+                }),
+            )
+            .into_pat(),
+            _ => pat_tuple_struct(
+                variant_path(variant),
+                bindings.iter().cloned().map(pat_ident),
+            )
+            .into_pat(),
+        };
+        let value = match bindings.as_slice() {
+            [binding] => expr_path(binding.clone()).into_expr(),
+            _ => expr_tuple(bindings.iter().cloned().map(expr_path)).into_expr(),
+        };
+
+        let mut match_ =
+            expr_match(expr_path("self")).arm(pat, expr_call(expr_path("Some"), [value]));
+        if self.item.variants.len() > 1 {
+            match_ = match_.wildcard(expr_path("None"));
+        }
+        match_.into_expr()
+    }
+
+    fn variant_name_fn(&self) -> ImplItem {
+        let names = self
+            .match_self(|variant| expr_lit(variant.ident.unraw().to_string().as_str()).into_expr());
+        impl_item_fn(
+            signature("variant_name", [self_ref()]).output("&'static str"),
+            block([names.into_expr()]),
+        )
+        .vis(self.item.vis.clone())
+        .into_impl_item()
+    }
+
+    fn match_self(&self, body: impl Fn(&Variant) -> Expr) -> ExprMatch {
+        if self.item.variants.is_empty() {
+            return expr_match(expr_unary(un_op_deref(), expr_path("self")));
+        }
+
+        expr_match(expr_path("self")).arms(
+            self.item
+                .variants
+                .iter()
+                .map(|variant| (variant_pat(variant), body(variant))),
+        )
+    }
+
+    fn display_name(&self, variant: &Variant) -> String {
+        self.casing.apply(&variant.ident.unraw().to_string())
+    }
+
+    fn display_impl(&self) -> ItemImpl {
+        let name =
+            self.match_self(|variant| expr_lit(self.display_name(variant).as_str()).into_expr());
+        let (formatter, body) = if self.item.variants.is_empty() {
+            ("_f", name.into_expr())
+        } else {
+            (
+                "f",
+                expr_method_call(expr_path("f"), "write_str", [name]).into_expr(),
+            )
+        };
+
+        self.item_impl()
+            .trait_(false, "::core::fmt::Display")
+            .item(impl_item_fn(fmt_signature(formatter), block([body])))
+    }
+
+    fn parse_impl(&self) -> ItemImpl {
+        let error = expr_call(
+            expr_path(self.error.clone()),
+            [expr_method_call(
+                expr_path("s"),
+                "to_string",
+                Vec::<Expr>::new(),
+            )],
+        );
+        let parse = expr_match(expr_path("s")).arms(self.item.variants.iter().map(|variant| {
+            (
+                pat_lit(self.display_name(variant).as_str()).into_pat(),
+                expr_call(expr_path("Ok"), [expr_path(variant_path(variant))]).into_expr(),
+            )
+        }));
+        let error_ty = type_path(self.error.clone()).into_type();
+
+        self.item_impl()
+            .trait_(false, "::core::str::FromStr")
+            .item(impl_item_type("Err", error_ty.clone()))
+            .item(impl_item_fn(
+                signature("from_str", [arg("s", "&str")]).output(result("Self", error_ty)),
+                block([or_else(parse, expr_call(expr_path("Err"), [error]))]),
+            ))
+    }
+
+    fn try_from_impl(&self, repr: Ident) -> ItemImpl {
+        let mut convert = expr_match(expr_path("value"));
+        for variant in &self.item.variants {
+            let ok = expr_call(expr_path("Ok"), [expr_path(variant_path(variant))]);
+            convert = match &variant.discriminant {
+                Some((_, Expr::Lit(lit))) if matches!(lit.lit, Lit::Int(_)) => {
+                    convert.arm(pat_lit(lit.lit.clone()), ok)
+                }
+                _ => convert.arm_guard(
+                    pat_wild(),
+                    expr_binary(
+                        expr_path("value"),
+                        bin_op_eq_variant(),
+                        expr_cast(expr_path(variant_path(variant)), type_path(repr.clone())),
+                    ),
+                    ok,
+                ),
+            };
+        }
+        let repr_ty = type_path(repr).into_type();
+
+        let trait_ = path([
+            path_segment("core"),
+            path_segment("convert"),
+            path_segment("TryFrom").arguments(angle_bracketed_generic_arguments([repr_ty.clone()])),
+        ])
+        .leading_colon(true);
+
+        self.item_impl()
+            .trait_(false, trait_)
+            .item(impl_item_type("Error", repr_ty.clone()))
+            .item(impl_item_fn(
+                signature("try_from", [arg("value", repr_ty.clone())])
+                    .output(result("Self", repr_ty)),
+                block([or_else(
+                    convert,
+                    expr_call(expr_path("Err"), [expr_path("value")]),
+                )]),
+            ))
+    }
+}
+
+fn variant_path(variant: &Variant) -> Path {
+    path([path_segment("Self"), path_segment(variant.ident.clone())])
+}
+
+fn method_name(prefix: &str, variant: &Variant) -> Ident {
+    ident_snake_case(&format!("{prefix}_{}", variant.ident.unraw()))
+}
+
+fn variant_pat(variant: &Variant) -> Pat {
+    match &variant.fields {
+        Fields::Named(_) => pat_struct(variant_path(variant), Vec::<FieldPat>::new())
+            .rest(pat_rest())
+            .into_pat(),
+        Fields::Unnamed(_) => pat_tuple_struct(variant_path(variant), [pat_rest()]).into_pat(),
+        Fields::Unit => pat_path(variant_path(variant)).into_pat(),
+    }
+}
+
+fn fields_type(variant: &Variant, ty: impl Fn(Type) -> Type) -> Type {
+    let mut types: Vec<Type> = variant
+        .fields
+        .iter()
+        .map(|field| ty(field.ty.clone()))
+        .collect();
+    if types.len() == 1 {
+        return types.remove(0);
+    }
+    type_tuple(types).into_type()
+}
+
+fn or_else(match_: ExprMatch, fallback: impl IntoExpr) -> Expr {
+    if match_.arms.is_empty() {
+        return fallback.into_expr();
+    }
+    match_.wildcard(fallback).into_expr()
+}
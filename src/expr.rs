@@ -1,21 +1,25 @@
 use crate::{
-    attrs_builder, label_builder,
+    attrs_builder, block, current_span, label_builder,
     macros::{
         AttrsPropsBuilder, LabelPropsBuilder, MutabilityPropsBuilder, OutputPropsBuilder,
         QSelfPropsBuilder,
     },
-    mutability_builder, output_builder, qself_builder, IntoBinOp, IntoIdent, IntoLit, IntoPat,
-    IntoPath, IntoType, IntoUnOp,
+    mutability_builder, output_builder, pat_wild,
+    precedence::parenthesize_operands,
+    qself_builder,
+    span::{parse_spanned, punctuated, spanned},
+    stmt::is_block_like,
+    IntoBinOp, IntoIdent, IntoLit, IntoPat, IntoPath, IntoType, IntoUnOp,
 };
 use proc_macro2::TokenStream;
 use syn::{
-    token, AngleBracketedGenericArguments, Arm, Block, Expr, ExprArray, ExprAssign, ExprAsync,
-    ExprAwait, ExprBinary, ExprBlock, ExprBreak, ExprCall, ExprCast, ExprClosure, ExprConst,
-    ExprContinue, ExprField, ExprForLoop, ExprGroup, ExprIf, ExprIndex, ExprInfer, ExprLet,
-    ExprLit, ExprLoop, ExprMacro, ExprMatch, ExprMethodCall, ExprParen, ExprPath, ExprRange,
-    ExprReference, ExprRepeat, ExprReturn, ExprStruct, ExprTry, ExprTryBlock, ExprTuple, ExprUnary,
-    ExprUnsafe, ExprWhile, ExprYield, FieldValue, Index, Label, Lifetime, Macro, Member,
-    RangeLimits, ReturnType,
+    punctuated::Punctuated, token, AngleBracketedGenericArguments, Arm, Block, BoundLifetimes,
+    Expr, ExprArray, ExprAssign, ExprAsync, ExprAwait, ExprBinary, ExprBlock, ExprBreak, ExprCall,
+    ExprCast, ExprClosure, ExprConst, ExprContinue, ExprField, ExprForLoop, ExprGroup, ExprIf,
+    ExprIndex, ExprInfer, ExprLet, ExprLit, ExprLoop, ExprMacro, ExprMatch, ExprMethodCall,
+    ExprParen, ExprPath, ExprRange, ExprReference, ExprRepeat, ExprReturn, ExprStruct, ExprTry,
+    ExprTryBlock, ExprTuple, ExprUnary, ExprUnsafe, ExprWhile, ExprYield, FieldValue, Index, Label,
+    Lifetime, Macro, Member, RangeLimits, ReturnType, Stmt,
 };
 
 pub trait IntoExpr {
@@ -28,6 +32,16 @@ impl IntoExpr for Expr {
     }
 }
 
+impl IntoExpr for Macro {
+    fn into_expr(self) -> Expr {
+        Expr::Macro(expr_macro(self))
+    }
+}
+
+pub fn try_expr(expr: &str) -> syn::Result<Expr> {
+    parse_spanned(expr)
+}
+
 macro_rules! impl_into_expr {
     ($($target:ident($type:ty),)*) => {
         $(
@@ -85,8 +99,8 @@ impl_into_expr!(
 pub fn expr_array<I: IntoExpr>(elems: impl IntoIterator<Item = I>) -> ExprArray {
     ExprArray {
         attrs: Default::default(),
-        bracket_token: Default::default(),
-        elems: FromIterator::from_iter(elems.into_iter().map(IntoExpr::into_expr)),
+        bracket_token: spanned(),
+        elems: punctuated(elems.into_iter().map(IntoExpr::into_expr)),
     }
 }
 
@@ -106,7 +120,7 @@ pub fn expr_assign(left: impl IntoExpr, right: impl IntoExpr) -> ExprAssign {
     ExprAssign {
         attrs: Default::default(),
         left: left.into_expr().into(),
-        eq_token: Default::default(),
+        eq_token: spanned(),
         right: right.into_expr().into(),
     }
 }
@@ -126,7 +140,7 @@ impl ExprAssignBuilder for ExprAssign {
 pub fn expr_async(block: impl Into<Block>) -> ExprAsync {
     ExprAsync {
         attrs: Default::default(),
-        async_token: Default::default(),
+        async_token: spanned(),
         capture: None,
         block: block.into(),
     }
@@ -146,7 +160,7 @@ impl ExprAsyncBuilder for ExprAsync {
 
     fn capture(self, capture: bool) -> Self {
         Self {
-            capture: capture.then(Default::default),
+            capture: capture.then(spanned),
             ..self
         }
     }
@@ -156,8 +170,8 @@ pub fn expr_await(base: impl IntoExpr) -> ExprAwait {
     ExprAwait {
         attrs: Default::default(),
         base: base.into_expr().into(),
-        dot_token: Default::default(),
-        await_token: Default::default(),
+        dot_token: spanned(),
+        await_token: spanned(),
     }
 }
 
@@ -218,7 +232,7 @@ impl ExprBlockBuilder for ExprBlock {
 pub fn expr_break() -> ExprBreak {
     ExprBreak {
         attrs: Default::default(),
-        break_token: Default::default(),
+        break_token: spanned(),
         label: None,
         expr: None,
     }
@@ -256,8 +270,8 @@ pub fn expr_call<I: IntoExpr>(func: impl IntoExpr, args: impl IntoIterator<Item
     ExprCall {
         attrs: Default::default(),
         func: func.into_expr().into(),
-        paren_token: Default::default(),
-        args: FromIterator::from_iter(args.into_iter().map(IntoExpr::into_expr)),
+        paren_token: spanned(),
+        args: punctuated(args.into_iter().map(IntoExpr::into_expr)),
     }
 }
 
@@ -277,7 +291,7 @@ pub fn expr_cast(expr: impl IntoExpr, ty: impl IntoType) -> ExprCast {
     ExprCast {
         attrs: Default::default(),
         expr: expr.into_expr().into(),
-        as_token: Default::default(),
+        as_token: spanned(),
         ty: ty.into_type().into(),
     }
 }
@@ -305,9 +319,9 @@ pub fn expr_closure<I: IntoPat>(
         movability: None,
         asyncness: None,
         capture: None,
-        or1_token: Default::default(),
-        inputs: FromIterator::from_iter(inputs.into_iter().map(IntoPat::into_pat)),
-        or2_token: Default::default(),
+        or1_token: spanned(),
+        inputs: punctuated(inputs.into_iter().map(IntoPat::into_pat)),
+        or2_token: spanned(),
         output: ReturnType::Default,
         body: body.into_expr().into(),
     }
@@ -318,7 +332,7 @@ output_builder!(ExprClosure);
 
 pub trait ExprClosureBuilder: AttrsPropsBuilder + OutputPropsBuilder {
     fn new<I: IntoPat>(inputs: impl IntoIterator<Item = I>, body: impl IntoExpr) -> Self;
-    fn lifetimes(self, lifetimes: bool) -> Self;
+    fn lifetimes(self, lifetimes: impl Into<BoundLifetimes>) -> Self;
     fn constness(self, constness: bool) -> Self;
     fn movability(self, movability: bool) -> Self;
     fn asyncness(self, asyncness: bool) -> Self;
@@ -330,37 +344,37 @@ impl ExprClosureBuilder for ExprClosure {
         expr_closure(inputs, body)
     }
 
-    fn lifetimes(self, lifetimes: bool) -> Self {
+    fn lifetimes(self, lifetimes: impl Into<BoundLifetimes>) -> Self {
         Self {
-            lifetimes: lifetimes.then(Default::default),
+            lifetimes: Some(lifetimes.into()),
             ..self
         }
     }
 
     fn constness(self, constness: bool) -> Self {
         Self {
-            constness: constness.then(Default::default),
+            constness: constness.then(spanned),
             ..self
         }
     }
 
     fn movability(self, movability: bool) -> Self {
         Self {
-            movability: movability.then(Default::default),
+            movability: movability.then(spanned),
             ..self
         }
     }
 
     fn asyncness(self, asyncness: bool) -> Self {
         Self {
-            asyncness: asyncness.then(Default::default),
+            asyncness: asyncness.then(spanned),
             ..self
         }
     }
 
     fn capture(self, capture: bool) -> Self {
         Self {
-            capture: capture.then(Default::default),
+            capture: capture.then(spanned),
             ..self
         }
     }
@@ -369,7 +383,7 @@ impl ExprClosureBuilder for ExprClosure {
 pub fn expr_const(block: impl Into<Block>) -> ExprConst {
     ExprConst {
         attrs: Default::default(),
-        const_token: Default::default(),
+        const_token: spanned(),
         block: block.into(),
     }
 }
@@ -389,7 +403,7 @@ impl ExprConstBuilder for ExprConst {
 pub fn expr_continue() -> ExprContinue {
     ExprContinue {
         attrs: Default::default(),
-        continue_token: Default::default(),
+        continue_token: spanned(),
         label: None,
     }
 }
@@ -418,7 +432,7 @@ pub fn expr_field(base: impl IntoExpr, member: impl Into<Member>) -> ExprField {
     ExprField {
         attrs: Default::default(),
         base: base.into_expr().into(),
-        dot_token: Default::default(),
+        dot_token: spanned(),
         member: member.into(),
     }
 }
@@ -443,9 +457,9 @@ pub fn expr_for_loop(
     ExprForLoop {
         attrs: Default::default(),
         label: None,
-        for_token: Default::default(),
+        for_token: spanned(),
         pat: pat.into_pat().into(),
-        in_token: Default::default(),
+        in_token: spanned(),
         expr: expr.into_expr().into(),
         body: body.into(),
     }
@@ -467,7 +481,7 @@ impl ExprForLoopBuilder for ExprForLoop {
 pub fn expr_group(expr: impl IntoExpr) -> ExprGroup {
     ExprGroup {
         attrs: Default::default(),
-        group_token: Default::default(),
+        group_token: spanned(),
         expr: expr.into_expr().into(),
     }
 }
@@ -487,7 +501,7 @@ impl ExprGroupBuilder for ExprGroup {
 pub fn expr_if(cond: impl IntoExpr, then_branch: impl Into<Block>) -> ExprIf {
     ExprIf {
         attrs: Default::default(),
-        if_token: Default::default(),
+        if_token: spanned(),
         cond: cond.into_expr().into(),
         then_branch: then_branch.into(),
         else_branch: None,
@@ -508,17 +522,74 @@ impl ExprIfBuilder for ExprIf {
 
     fn else_branch(self, expr: impl IntoExpr) -> Self {
         Self {
-            else_branch: Some((Default::default(), expr.into_expr().into())),
+            else_branch: Some((spanned(), expr.into_expr().into())),
+            ..self
+        }
+    }
+}
+
+#[derive(Clone, Default)]
+pub struct IfChain {
+    branches: Vec<(Expr, Block)>,
+    otherwise: Option<Block>,
+}
+
+pub fn if_chain() -> IfChain {
+    IfChain::default()
+}
+
+impl IfChain {
+    pub fn when(mut self, cond: impl IntoExpr, then_branch: impl Into<Block>) -> Self {
+        self.branches.push((cond.into_expr(), then_branch.into()));
+        self
+    }
+
+    pub fn when_let(
+        self,
+        pat: impl IntoPat,
+        expr: impl IntoExpr,
+        then_branch: impl Into<Block>,
+    ) -> Self {
+        let mut cond = Expr::Let(expr_let(pat, expr));
+        parenthesize_operands(&mut cond);
+        self.when(cond, then_branch)
+    }
+
+    pub fn otherwise(self, else_branch: impl Into<Block>) -> Self {
+        Self {
+            otherwise: Some(else_branch.into()),
             ..self
         }
     }
 }
 
+impl IntoExpr for IfChain {
+    fn into_expr(self) -> Expr {
+        let otherwise = self
+            .otherwise
+            .map(|else_branch| Expr::Block(expr_block(else_branch)));
+
+        self.branches
+            .into_iter()
+            .rev()
+            .fold(otherwise, |else_branch, (cond, then_branch)| {
+                let if_ = expr_if(cond, then_branch);
+                let mut expr = Expr::If(match else_branch {
+                    Some(else_branch) => if_.else_branch(else_branch),
+                    None => if_,
+                });
+                parenthesize_operands(&mut expr);
+                Some(expr)
+            })
+            .unwrap_or_else(|| Expr::Block(expr_block(block::<Stmt>([]))))
+    }
+}
+
 pub fn expr_index(expr: impl IntoExpr, index: impl IntoExpr) -> ExprIndex {
     ExprIndex {
         attrs: Default::default(),
         expr: expr.into_expr().into(),
-        bracket_token: Default::default(),
+        bracket_token: spanned(),
         index: index.into_expr().into(),
     }
 }
@@ -538,7 +609,7 @@ impl ExprIndexBuilder for ExprIndex {
 pub fn expr_infer() -> ExprInfer {
     ExprInfer {
         attrs: Default::default(),
-        underscore_token: Default::default(),
+        underscore_token: spanned(),
     }
 }
 
@@ -557,9 +628,9 @@ impl ExprInferBuilder for ExprInfer {
 pub fn expr_let(pat: impl IntoPat, expr: impl IntoExpr) -> ExprLet {
     ExprLet {
         attrs: Default::default(),
-        let_token: Default::default(),
+        let_token: spanned(),
         pat: pat.into_pat().into(),
-        eq_token: Default::default(),
+        eq_token: spanned(),
         expr: expr.into_expr().into(),
     }
 }
@@ -599,7 +670,7 @@ pub fn expr_loop(body: impl Into<Block>) -> ExprLoop {
     ExprLoop {
         attrs: Default::default(),
         label: None,
-        loop_token: Default::default(),
+        loop_token: spanned(),
         body: body.into(),
     }
 }
@@ -636,25 +707,46 @@ impl ExprMacroBuilder for ExprMacro {
     }
 }
 
-pub fn expr_match(expr: impl IntoExpr, arms: impl IntoIterator<Item = Arm>) -> ExprMatch {
+pub fn expr_match(expr: impl IntoExpr) -> ExprMatch {
     ExprMatch {
         attrs: Default::default(),
-        match_token: Default::default(),
+        match_token: spanned(),
         expr: expr.into_expr().into(),
-        brace_token: Default::default(),
-        arms: arms.into_iter().collect(),
+        brace_token: spanned(),
+        arms: Default::default(),
     }
 }
 
 attrs_builder!(ExprMatch);
 
 pub trait ExprMatchBuilder: AttrsPropsBuilder {
-    fn new(expr: impl IntoExpr, arms: impl IntoIterator<Item = Arm>) -> Self;
+    fn new(expr: impl IntoExpr) -> Self;
+    fn arms<A: IntoArm>(self, arms: impl IntoIterator<Item = A>) -> Self;
+    fn arm(self, pat: impl IntoPat, body: impl IntoExpr) -> Self;
+    fn arm_guard(self, pat: impl IntoPat, guard: impl IntoExpr, body: impl IntoExpr) -> Self;
+    fn wildcard(self, body: impl IntoExpr) -> Self;
 }
 
 impl ExprMatchBuilder for ExprMatch {
-    fn new(expr: impl IntoExpr, arms: impl IntoIterator<Item = Arm>) -> Self {
-        expr_match(expr, arms)
+    fn new(expr: impl IntoExpr) -> Self {
+        expr_match(expr)
+    }
+
+    fn arms<A: IntoArm>(mut self, arms: impl IntoIterator<Item = A>) -> Self {
+        self.arms.extend(arms.into_iter().map(IntoArm::into_arm));
+        self
+    }
+
+    fn arm(self, pat: impl IntoPat, body: impl IntoExpr) -> Self {
+        self.arms([arm(pat, body)])
+    }
+
+    fn arm_guard(self, pat: impl IntoPat, guard: impl IntoExpr, body: impl IntoExpr) -> Self {
+        self.arms([arm(pat, body).guard(guard)])
+    }
+
+    fn wildcard(self, body: impl IntoExpr) -> Self {
+        self.arm(pat_wild(), body)
     }
 }
 
@@ -666,11 +758,11 @@ pub fn expr_method_call<A: IntoExpr>(
     ExprMethodCall {
         attrs: Default::default(),
         receiver: receiver.into_expr().into(),
-        dot_token: Default::default(),
+        dot_token: spanned(),
         method: method.into_ident(),
         turbofish: None,
-        paren_token: Default::default(),
-        args: FromIterator::from_iter(args.into_iter().map(IntoExpr::into_expr)),
+        paren_token: spanned(),
+        args: punctuated(args.into_iter().map(IntoExpr::into_expr)),
     }
 }
 
@@ -696,7 +788,10 @@ impl ExprMethodCallBuilder for ExprMethodCall {
 
     fn turbofish(self, args: impl Into<AngleBracketedGenericArguments>) -> Self {
         Self {
-            turbofish: Some(args.into()),
+            turbofish: Some(AngleBracketedGenericArguments {
+                colon2_token: Some(spanned()),
+                ..args.into()
+            }),
             ..self
         }
     }
@@ -705,7 +800,7 @@ impl ExprMethodCallBuilder for ExprMethodCall {
 pub fn expr_paren(expr: impl IntoExpr) -> ExprParen {
     ExprParen {
         attrs: Default::default(),
-        paren_token: Default::default(),
+        paren_token: spanned(),
         expr: expr.into_expr().into(),
     }
 }
@@ -716,6 +811,12 @@ pub trait ExprParenBuilder: AttrsPropsBuilder {
     fn new(expr: impl IntoExpr) -> Self;
 }
 
+impl ExprParenBuilder for ExprParen {
+    fn new(expr: impl IntoExpr) -> Self {
+        expr_paren(expr)
+    }
+}
+
 pub fn expr_path(path: impl IntoPath) -> ExprPath {
     ExprPath {
         attrs: Default::default(),
@@ -761,7 +862,7 @@ impl ExprRangeBuilder for ExprRange {
 
     fn start(self, expr: impl IntoExpr) -> Self {
         Self {
-            end: Some(expr.into_expr().into()),
+            start: Some(expr.into_expr().into()),
             ..self
         }
     }
@@ -777,7 +878,7 @@ impl ExprRangeBuilder for ExprRange {
 pub fn expr_reference(expr: impl IntoExpr) -> ExprReference {
     ExprReference {
         attrs: Default::default(),
-        and_token: Default::default(),
+        and_token: spanned(),
         mutability: None,
         expr: expr.into_expr().into(),
     }
@@ -799,9 +900,9 @@ impl ExprReferenceBuilder for ExprReference {
 pub fn expr_repeat(expr: impl IntoExpr, len: impl IntoExpr) -> ExprRepeat {
     ExprRepeat {
         attrs: Default::default(),
-        bracket_token: Default::default(),
+        bracket_token: spanned(),
         expr: expr.into_expr().into(),
-        semi_token: Default::default(),
+        semi_token: spanned(),
         len: len.into_expr().into(),
     }
 }
@@ -821,7 +922,7 @@ impl ExprRepeatBuilder for ExprRepeat {
 pub fn expr_return() -> ExprReturn {
     ExprReturn {
         attrs: Default::default(),
-        return_token: Default::default(),
+        return_token: spanned(),
         expr: None,
     }
 }
@@ -854,8 +955,8 @@ pub fn expr_struct<F: Into<FieldValue>>(
         attrs: Default::default(),
         qself: None,
         path: path.into_path(),
-        brace_token: Default::default(),
-        fields: FromIterator::from_iter(fields.into_iter().map(Into::into)),
+        brace_token: spanned(),
+        fields: punctuated(fields.into_iter().map(Into::into)),
         dot2_token: None,
         rest: None,
     }
@@ -875,18 +976,21 @@ impl ExprStructBuilder for ExprStruct {
         expr_struct(path, fields)
     }
 
-    fn dot2_token(self, dot2_token: bool) -> Self {
+    fn dot2_token(mut self, dot2_token: bool) -> Self {
+        if dot2_token && !self.fields.empty_or_trailing() {
+            self.fields.push_punct(spanned());
+        }
+
         Self {
-            dot2_token: dot2_token.then(Default::default),
+            dot2_token: dot2_token.then(spanned),
             ..self
         }
     }
 
     fn rest(self, rest: impl IntoExpr) -> Self {
         Self {
-            dot2_token: Some(Default::default()),
             rest: Some(rest.into_expr().into()),
-            ..self
+            ..self.dot2_token(true)
         }
     }
 }
@@ -895,7 +999,7 @@ pub fn expr_try(expr: impl IntoExpr) -> ExprTry {
     ExprTry {
         attrs: Default::default(),
         expr: expr.into_expr().into(),
-        question_token: Default::default(),
+        question_token: spanned(),
     }
 }
 
@@ -914,7 +1018,7 @@ impl ExprTryBuilder for ExprTry {
 pub fn expr_try_block(block: impl Into<Block>) -> ExprTryBlock {
     ExprTryBlock {
         attrs: vec![],
-        try_token: Default::default(),
+        try_token: spanned(),
         block: block.into(),
     }
 }
@@ -932,10 +1036,15 @@ impl ExprTryBlockBuilder for ExprTryBlock {
 }
 
 pub fn expr_tuple<E: IntoExpr>(elems: impl IntoIterator<Item = E>) -> ExprTuple {
+    let mut elems: Punctuated<_, _> = punctuated(elems.into_iter().map(IntoExpr::into_expr));
+    if elems.len() == 1 {
+        elems.push_punct(spanned());
+    }
+
     ExprTuple {
         attrs: Default::default(),
-        paren_token: Default::default(),
-        elems: FromIterator::from_iter(elems.into_iter().map(IntoExpr::into_expr)),
+        paren_token: spanned(),
+        elems,
     }
 }
 
@@ -974,7 +1083,7 @@ impl ExprUnaryBuilder for ExprUnary {
 pub fn expr_unsafe(block: impl Into<Block>) -> ExprUnsafe {
     ExprUnsafe {
         attrs: Default::default(),
-        unsafe_token: Default::default(),
+        unsafe_token: spanned(),
         block: block.into(),
     }
 }
@@ -995,7 +1104,7 @@ pub fn expr_while(cond: impl IntoExpr, body: impl Into<Block>) -> ExprWhile {
     ExprWhile {
         attrs: Default::default(),
         label: None,
-        while_token: Default::default(),
+        while_token: spanned(),
         cond: cond.into_expr().into(),
         body: body.into(),
     }
@@ -1017,7 +1126,7 @@ impl ExprWhileBuilder for ExprWhile {
 pub fn expr_yield() -> ExprYield {
     ExprYield {
         attrs: Default::default(),
-        yield_token: Default::default(),
+        yield_token: spanned(),
         expr: None,
     }
 }
@@ -1043,7 +1152,10 @@ impl ExprYieldBuilder for ExprYield {
 }
 
 pub fn index(i: impl Into<usize>) -> Index {
-    Index::from(i.into())
+    Index {
+        span: current_span(),
+        ..Index::from(i.into())
+    }
 }
 
 pub trait IndexBuilder {
@@ -1060,7 +1172,7 @@ pub fn field_value(member: impl Into<Member>, expr: impl IntoExpr) -> FieldValue
     FieldValue {
         attrs: Default::default(),
         member: member.into(),
-        colon_token: Some(Default::default()),
+        colon_token: Some(spanned()),
         expr: expr.into_expr(),
     }
 }
@@ -1080,7 +1192,7 @@ impl FieldValueBuilder for FieldValue {
 pub fn label(name: impl Into<Lifetime>) -> Label {
     Label {
         name: name.into(),
-        colon_token: Default::default(),
+        colon_token: spanned(),
     }
 }
 
@@ -1095,13 +1207,14 @@ impl LabelBuilder for Label {
 }
 
 pub fn arm(pat: impl IntoPat, body: impl IntoExpr) -> Arm {
+    let body = body.into_expr();
     Arm {
         attrs: Default::default(),
         pat: pat.into_pat(),
         guard: None,
-        fat_arrow_token: Default::default(),
-        body: body.into_expr().into(),
-        comma: Some(Default::default()),
+        fat_arrow_token: spanned(),
+        comma: (!is_block_like(&body)).then(spanned),
+        body: body.into(),
     }
 }
 
@@ -1119,18 +1232,34 @@ impl ArmBuilder for Arm {
 
     fn guard(self, expr: impl IntoExpr) -> Self {
         Self {
-            guard: Some((Default::default(), expr.into_expr().into())),
+            guard: Some((spanned(), expr.into_expr().into())),
             ..self
         }
     }
 }
 
+pub trait IntoArm {
+    fn into_arm(self) -> Arm;
+}
+
+impl IntoArm for Arm {
+    fn into_arm(self) -> Arm {
+        self
+    }
+}
+
+impl<P: IntoPat, B: IntoExpr> IntoArm for (P, B) {
+    fn into_arm(self) -> Arm {
+        arm(self.0, self.1)
+    }
+}
+
 pub fn range_limits_half_open_variant() -> RangeLimits {
-    RangeLimits::HalfOpen(Default::default())
+    RangeLimits::HalfOpen(spanned())
 }
 
 pub fn range_limits_closed_variant() -> RangeLimits {
-    RangeLimits::Closed(Default::default())
+    RangeLimits::Closed(spanned())
 }
 
 pub trait IntoRangeLimits {
@@ -1,4 +1,8 @@
-use crate::{attrs_builder, macros::AttrsPropsBuilder, IntoItem};
+use crate::{
+    attrs_builder, inner_doc_builder,
+    macros::{AttrsPropsBuilder, InnerDocPropsBuilder},
+    IntoItem,
+};
 use syn::File;
 
 pub fn file<I: IntoItem>(items: impl IntoIterator<Item = I>) -> File {
@@ -9,9 +13,10 @@ pub fn file<I: IntoItem>(items: impl IntoIterator<Item = I>) -> File {
     }
 }
 
-attrs_builder!(File);
+attrs_builder!(inner File);
+inner_doc_builder!(File);
 
-pub trait FileBuilder: AttrsPropsBuilder {
+pub trait FileBuilder: AttrsPropsBuilder + InnerDocPropsBuilder {
     fn new<I: IntoItem>(items: impl IntoIterator<Item = I>) -> Self;
     fn shebang(self, shebang: impl Into<String>) -> Self;
 }
@@ -0,0 +1,68 @@
+use crate::{
+    angle_bracketed_generic_arguments, arg, block, expr_field, expr_lit, expr_path, field,
+    fields_unamed, impl_item_fn, index, item_impl, item_struct, macro_call, path, path_segment,
+    self_ref, signature, type_path, visibility_public_variant, DerivePropsBuilder, IntoExpr,
+    IntoFnArg, IntoType, ItemImplBuilder, OutputPropsBuilder, PathSeqmentBuilder, VisPropsBuilder,
+};
+use syn::{Attribute, Item, Signature, Type};
+
+pub(crate) fn docs(attrs: &[Attribute]) -> Vec<Attribute> {
+    attrs
+        .iter()
+        .filter(|attr| attr.path().is_ident("doc"))
+        .cloned()
+        .collect()
+}
+
+pub(crate) fn option(ty: impl IntoType) -> Type {
+    type_path(path([
+        path_segment("Option").arguments(angle_bracketed_generic_arguments([ty.into_type()]))
+    ]))
+    .into_type()
+}
+
+pub(crate) fn result(ok: impl IntoType, error: impl IntoType) -> Type {
+    type_path(path([path_segment("Result").arguments(
+        angle_bracketed_generic_arguments([ok.into_type(), error.into_type()]),
+    )]))
+    .into_type()
+}
+
+pub(crate) fn fmt_signature(formatter: &str) -> Signature {
+    signature(
+        "fmt",
+        [
+            self_ref().into_fn_arg(),
+            arg(formatter, "&mut ::core::fmt::Formatter<'_>").into_fn_arg(),
+        ],
+    )
+    .output("::core::fmt::Result")
+}
+
+pub(crate) fn error_struct(name: &str, ty: &str, derives: &[&str], message: &str) -> Vec<Item> {
+    let error = item_struct(
+        name,
+        fields_unamed([field(ty).vis(visibility_public_variant())]),
+    )
+    .vis(visibility_public_variant())
+    .derive(derives.iter().copied());
+
+    let display = item_impl(name)
+        .trait_(false, "::core::fmt::Display")
+        .item(impl_item_fn(
+            fmt_signature("f"),
+            block([macro_call(
+                "write",
+                [
+                    expr_path("f").into_expr(),
+                    expr_lit(message).into_expr(),
+                    expr_field(expr_path("self"), index(0usize)).into_expr(),
+                ],
+            )
+            .into_expr()]),
+        ));
+
+    let std_error = item_impl(name).trait_(false, "::std::error::Error");
+
+    vec![error.into(), display.into(), std_error.into()]
+}
@@ -1,18 +1,25 @@
-use crate::{attrs_builder, macros::AttrsPropsBuilder, IntoExpr, IntoIdent, IntoPath, IntoType};
-use proc_macro2::TokenStream;
+use crate::{
+    angle_bracketed_generic_arguments, attrs_builder,
+    macros::AttrsPropsBuilder,
+    path, path_segment,
+    span::{punctuated, spanned},
+    type_path, IntoExpr, IntoIdent, IntoPath, IntoType, PathSeqmentBuilder,
+};
+use proc_macro2::{Ident, TokenStream};
 use syn::{
-    BoundLifetimes, ConstParam, GenericParam, Generics, Lifetime, LifetimeParam, PredicateLifetime,
-    PredicateType, TraitBound, TraitBoundModifier, TypeParam, TypeParamBound, WhereClause,
-    WherePredicate,
+    punctuated::Punctuated, BoundLifetimes, ConstParam, GenericArgument, GenericParam, Generics,
+    Lifetime, LifetimeParam, Path, PredicateLifetime, PredicateType, TraitBound,
+    TraitBoundModifier, TypeParam, TypeParamBound, WhereClause, WherePredicate,
 };
 
 pub fn generics<P: IntoGenericParam>(params: impl IntoIterator<Item = P>) -> Generics {
+    let params: Punctuated<_, _> =
+        punctuated(params.into_iter().map(IntoGenericParam::into_generic_param));
+
     Generics {
-        lt_token: Some(Default::default()),
-        params: FromIterator::from_iter(
-            params.into_iter().map(IntoGenericParam::into_generic_param),
-        ),
-        gt_token: Some(Default::default()),
+        lt_token: (!params.is_empty()).then(spanned),
+        gt_token: (!params.is_empty()).then(spanned),
+        params,
         ..Default::default()
     }
 }
@@ -73,7 +80,7 @@ impl LifetimeParamBuilder for LifetimeParam {
 
     fn bounds<B: Into<Lifetime>>(self, bounds: impl IntoIterator<Item = B>) -> Self {
         Self {
-            colon_token: Some(Default::default()),
+            colon_token: Some(spanned()),
             bounds: bounds.into_iter().map(Into::into).collect(),
             ..self
         }
@@ -112,8 +119,8 @@ impl TypeParamBuilder for TypeParam {
 
     fn bounds<B: IntoTypeParamBound>(self, bounds: impl IntoIterator<Item = B>) -> Self {
         Self {
-            colon_token: Some(Default::default()),
-            bounds: FromIterator::from_iter(
+            colon_token: Some(spanned()),
+            bounds: punctuated(
                 bounds
                     .into_iter()
                     .map(IntoTypeParamBound::into_type_param_bound),
@@ -124,7 +131,7 @@ impl TypeParamBuilder for TypeParam {
 
     fn default(self, default: impl IntoType) -> Self {
         Self {
-            eq_token: Some(Default::default()),
+            eq_token: Some(spanned()),
             default: Some(default.into_type()),
             ..self
         }
@@ -134,9 +141,9 @@ impl TypeParamBuilder for TypeParam {
 pub fn const_param(ident: impl IntoIdent, ty: impl IntoType) -> ConstParam {
     ConstParam {
         attrs: Default::default(),
-        const_token: Default::default(),
+        const_token: spanned(),
         ident: ident.into_ident(),
-        colon_token: Default::default(),
+        colon_token: spanned(),
         ty: ty.into_type(),
         eq_token: None,
         default: None,
@@ -163,7 +170,7 @@ impl ConstParamBuilder for ConstParam {
 
     fn default(self, default: impl IntoExpr) -> Self {
         Self {
-            eq_token: Some(Default::default()),
+            eq_token: Some(spanned()),
             default: Some(default.into_expr()),
             ..self
         }
@@ -174,12 +181,14 @@ pub fn bound_lifetimes<L: IntoGenericParam>(
     lifetimes: impl IntoIterator<Item = L>,
 ) -> BoundLifetimes {
     BoundLifetimes {
-        lifetimes: FromIterator::from_iter(
+        for_token: spanned(),
+        lt_token: spanned(),
+        lifetimes: punctuated(
             lifetimes
                 .into_iter()
                 .map(IntoGenericParam::into_generic_param),
         ),
-        ..Default::default()
+        gt_token: spanned(),
     }
 }
 
@@ -251,7 +260,7 @@ impl TraitBoundBuilder for TraitBound {
     fn modifier(self, maybe: bool) -> Self {
         Self {
             modifier: if maybe {
-                TraitBoundModifier::Maybe(Default::default())
+                TraitBoundModifier::Maybe(spanned())
             } else {
                 TraitBoundModifier::None
             },
@@ -262,8 +271,8 @@ impl TraitBoundBuilder for TraitBound {
 
 pub fn where_clause<P: IntoWherePredicate>(predicates: impl IntoIterator<Item = P>) -> WhereClause {
     WhereClause {
-        where_token: Default::default(),
-        predicates: FromIterator::from_iter(
+        where_token: spanned(),
+        predicates: punctuated(
             predicates
                 .into_iter()
                 .map(IntoWherePredicate::into_where_predicate),
@@ -297,8 +306,8 @@ pub fn predicate_lifetime<B: Into<Lifetime>>(
 ) -> PredicateLifetime {
     PredicateLifetime {
         lifetime: lifetime.into(),
-        colon_token: Default::default(),
-        bounds: FromIterator::from_iter(bounds.into_iter().map(Into::into)),
+        colon_token: spanned(),
+        bounds: punctuated(bounds.into_iter().map(Into::into)),
     }
 }
 
@@ -331,8 +340,8 @@ pub fn predicate_type<B: IntoTypeParamBound>(
     PredicateType {
         lifetimes: None,
         bounded_ty: bounded_ty.into_type(),
-        colon_token: Default::default(),
-        bounds: FromIterator::from_iter(
+        colon_token: spanned(),
+        bounds: punctuated(
             bounds
                 .into_iter()
                 .map(IntoTypeParamBound::into_type_param_bound),
@@ -369,3 +378,38 @@ impl PredicateTypeBuilder for PredicateType {
         }
     }
 }
+
+pub(crate) fn impl_generics(generics: &Generics) -> Generics {
+    let mut generics = generics.clone();
+    for param in &mut generics.params {
+        match param {
+            GenericParam::Type(param) => {
+                param.eq_token = None;
+                param.default = None;
+            }
+            GenericParam::Const(param) => {
+                param.eq_token = None;
+                param.default = None;
+            }
+            GenericParam::Lifetime(_) => {}
+        }
+    }
+    generics
+}
+
+pub(crate) fn generic_path(ident: &Ident, generics: &Generics) -> Path {
+    let segment = path_segment(ident.clone());
+    if generics.params.is_empty() {
+        return path([segment]);
+    }
+
+    let args = generics.params.iter().map(|param| match param {
+        GenericParam::Lifetime(param) => GenericArgument::Lifetime(param.lifetime.clone()),
+        GenericParam::Type(TypeParam { ident, .. })
+        | GenericParam::Const(ConstParam { ident, .. }) => {
+            GenericArgument::Type(type_path(ident.clone()).into_type())
+        }
+    });
+
+    path([segment.arguments(angle_bracketed_generic_arguments(args))])
+}
@@ -1,4 +1,6 @@
-use proc_macro2::{Ident, Span};
+use crate::current_span;
+use proc_macro2::{Ident, TokenStream, TokenTree};
+use unicode_ident::{is_xid_continue, is_xid_start};
 
 pub trait IntoIdent {
     fn into_ident(self) -> Ident;
@@ -12,6 +14,203 @@ impl IntoIdent for Ident {
 
 impl IntoIdent for &str {
     fn into_ident(self) -> Ident {
-        Ident::new(self, Span::call_site())
+        Ident::new(self, current_span())
     }
 }
+
+pub trait TryIntoIdent {
+    fn try_into_ident(self) -> syn::Result<Ident>;
+}
+
+impl TryIntoIdent for Ident {
+    fn try_into_ident(self) -> syn::Result<Ident> {
+        Ok(self)
+    }
+}
+
+impl TryIntoIdent for &str {
+    fn try_into_ident(self) -> syn::Result<Ident> {
+        try_ident(self)
+    }
+}
+
+pub(crate) const KEYWORDS: &[&str] = &[
+    "abstract", "as", "async", "await", "become", "box", "break", "const", "continue", "crate",
+    "do", "dyn", "else", "enum", "extern", "false", "final", "fn", "for", "gen", "if", "impl",
+    "in", "let", "loop", "macro", "match", "mod", "move", "mut", "override", "priv", "pub", "ref",
+    "return", "self", "Self", "static", "struct", "super", "trait", "true", "try", "type",
+    "typeof", "unsafe", "unsized", "use", "virtual", "where", "while", "yield",
+];
+
+pub(crate) const NON_RAW_KEYWORDS: &[&str] = &["crate", "self", "Self", "super"];
+
+fn invalid_ident(name: &str, reason: &str) -> syn::Error {
+    syn::Error::new(
+        current_span(),
+        format!("invalid identifier `{name}`: {reason}"),
+    )
+}
+
+fn check_ident(name: &str) -> syn::Result<()> {
+    if name.is_empty() {
+        return Err(invalid_ident(name, "identifier cannot be empty"));
+    }
+
+    if name == "_" {
+        return Err(invalid_ident(name, "`_` is not a valid identifier"));
+    }
+
+    if name.starts_with(|c: char| c.is_ascii_digit()) {
+        return Err(invalid_ident(name, "identifier cannot start with a digit"));
+    }
+
+    let mut tokens = name
+        .parse::<TokenStream>()
+        .map_err(|_| invalid_ident(name, "contains characters not valid in an identifier"))?
+        .into_iter();
+
+    match (tokens.next(), tokens.next()) {
+        (Some(TokenTree::Ident(ident)), None) if ident == name => Ok(()),
+        _ => Err(invalid_ident(
+            name,
+            "contains characters not valid in an identifier",
+        )),
+    }
+}
+
+pub fn try_ident(name: &str) -> syn::Result<Ident> {
+    check_ident(name)?;
+
+    if let Some(raw) = name.strip_prefix("r#") {
+        return Ok(Ident::new_raw(raw, current_span()));
+    }
+
+    if NON_RAW_KEYWORDS.contains(&name) {
+        return Err(invalid_ident(
+            name,
+            "keyword cannot be used as an identifier",
+        ));
+    }
+
+    if KEYWORDS.contains(&name) {
+        return Err(invalid_ident(
+            name,
+            &format!("keyword cannot be used as an identifier, use `r#{name}` instead"),
+        ));
+    }
+
+    Ok(Ident::new(name, current_span()))
+}
+
+pub fn try_ident_escaped(name: &str) -> syn::Result<Ident> {
+    if KEYWORDS.contains(&name) && !NON_RAW_KEYWORDS.contains(&name) {
+        return Ok(Ident::new_raw(name, current_span()));
+    }
+
+    try_ident(name)
+}
+
+pub fn ident_raw(name: &str) -> Ident {
+    Ident::new_raw(name, current_span())
+}
+
+pub fn ident_safe(name: &str) -> Ident {
+    let mut name: String = name
+        .chars()
+        .map(|c| if is_xid_continue(c) { c } else { '_' })
+        .collect();
+
+    if name.is_empty() || name.starts_with(|c: char| !is_xid_start(c) && c != '_') {
+        name.insert(0, '_');
+    }
+
+    if name == "_" || NON_RAW_KEYWORDS.contains(&name.as_str()) {
+        name.push('_');
+    }
+
+    if KEYWORDS.contains(&name.as_str()) {
+        Ident::new_raw(&name, current_span())
+    } else {
+        Ident::new(&name, current_span())
+    }
+}
+
+pub fn ident_snake_case(name: &str) -> Ident {
+    ident_safe(&snake_case(name))
+}
+
+pub fn ident_upper_camel_case(name: &str) -> Ident {
+    ident_safe(&upper_camel_case(name))
+}
+
+pub fn ident_screaming_snake_case(name: &str) -> Ident {
+    ident_safe(&screaming_snake_case(name))
+}
+
+pub(crate) fn snake_case(name: &str) -> String {
+    words(name)
+        .iter()
+        .map(|word| word.to_lowercase())
+        .collect::<Vec<_>>()
+        .join("_")
+}
+
+pub(crate) fn upper_camel_case(name: &str) -> String {
+    words(name)
+        .iter()
+        .map(|word| {
+            let mut chars = word.chars();
+            chars
+                .next()
+                .map(|first| {
+                    first
+                        .to_uppercase()
+                        .chain(chars.flat_map(char::to_lowercase))
+                })
+                .into_iter()
+                .flatten()
+                .collect::<String>()
+        })
+        .collect()
+}
+
+pub(crate) fn screaming_snake_case(name: &str) -> String {
+    words(name)
+        .iter()
+        .map(|word| word.to_uppercase())
+        .collect::<Vec<_>>()
+        .join("_")
+}
+
+fn words(name: &str) -> Vec<String> {
+    let chars: Vec<char> = name.chars().collect();
+    let mut words = Vec::new();
+    let mut word = String::new();
+
+    for (i, &c) in chars.iter().enumerate() {
+        if !c.is_alphanumeric() {
+            if !word.is_empty() {
+                words.push(std::mem::take(&mut word));
+            }
+            continue;
+        }
+
+        if let Some(prev) = word.chars().last() {
+            let next_lower = chars.get(i + 1).is_some_and(|next| next.is_lowercase());
+            let boundary = c.is_uppercase()
+                && (prev.is_lowercase() || prev.is_numeric() || prev.is_uppercase() && next_lower);
+
+            if boundary {
+                words.push(std::mem::take(&mut word));
+            }
+        }
+
+        word.push(c);
+    }
+
+    if !word.is_empty() {
+        words.push(word);
+    }
+
+    words
+}
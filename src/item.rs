@@ -1,14 +1,20 @@
 use crate::{
-    attrs_builder,
-    macros::{AttrsPropsBuilder, OutputPropsBuilder, VisPropsBuilder},
-    output_builder, type_path, vis_builder, IntoExpr, IntoFields, IntoIdent, IntoPat, IntoPath,
-    IntoType, IntoTypeParamBound,
+    attrs_builder, derive_builder, inner_doc_builder,
+    mac::macro_semi,
+    macros::{
+        AttrsPropsBuilder, DerivePropsBuilder, InnerDocPropsBuilder, MutabilityPropsBuilder,
+        OutputPropsBuilder, VisPropsBuilder,
+    },
+    output_builder, pat_ident, pat_type,
+    span::{punctuated, push_punctuated, spanned},
+    type_path, type_reference, vis_builder, IntoExpr, IntoFields, IntoIdent, IntoPat, IntoPath,
+    IntoType, IntoTypeParamBound, TypeReferenceBuilder,
 };
 use proc_macro2::TokenStream;
 use syn::{
-    Abi, Block, FieldsNamed, FnArg, ForeignItem, ForeignItemFn, ForeignItemMacro,
-    ForeignItemStatic, ForeignItemType, Generics, ImplItem, ImplItemConst, ImplItemFn,
-    ImplItemMacro, ImplItemType, Item, ItemConst, ItemEnum, ItemExternCrate, ItemFn,
+    punctuated::Punctuated, Abi, Block, Fields, FieldsNamed, FnArg, ForeignItem, ForeignItemFn,
+    ForeignItemMacro, ForeignItemStatic, ForeignItemType, Generics, ImplItem, ImplItemConst,
+    ImplItemFn, ImplItemMacro, ImplItemType, Item, ItemConst, ItemEnum, ItemExternCrate, ItemFn,
     ItemForeignMod, ItemImpl, ItemMacro, ItemMod, ItemStatic, ItemStruct, ItemTrait,
     ItemTraitAlias, ItemType, ItemUnion, ItemUse, Lifetime, Macro, PatType, Receiver, ReturnType,
     Signature, StaticMutability, TraitItem, TraitItemConst, TraitItemFn, TraitItemMacro,
@@ -45,7 +51,7 @@ macro_rules! unsafety_builder {
             impl $crate::item::UnsafetyPropsBuilder for $name {
                 fn unsafety(self, unsafety: bool) -> Self {
                     Self {
-                        unsafety: unsafety.then(|| Default::default()),
+                        unsafety: unsafety.then(spanned),
                         ..self
                     }
                 }
@@ -64,7 +70,7 @@ macro_rules! defaultness_builder {
             impl $crate::item::DefaultnessPropsBuilder for $name {
                 fn defaultness(self, defaultness: bool) -> Self {
                     Self {
-                        defaultness: defaultness.then(|| Default::default()),
+                        defaultness: defaultness.then(spanned),
                         ..self
                     }
                 }
@@ -95,6 +101,17 @@ macro_rules! impl_into_item {
     };
 }
 
+impl IntoItem for Macro {
+    fn into_item(self) -> Item {
+        Item::Macro(ItemMacro {
+            attrs: Default::default(),
+            ident: None,
+            semi_token: macro_semi(&self),
+            mac: self,
+        })
+    }
+}
+
 impl_into_item!(
     Const(ItemConst),
     Enum(ItemEnum),
@@ -118,14 +135,14 @@ pub fn item_const(ident: impl IntoIdent, ty: impl IntoType, expr: impl IntoExpr)
     ItemConst {
         attrs: Default::default(),
         vis: Visibility::Inherited,
-        const_token: Default::default(),
+        const_token: spanned(),
         ident: ident.into_ident(),
         generics: Default::default(),
-        colon_token: Default::default(),
+        colon_token: spanned(),
         ty: ty.into_type().into(),
-        eq_token: Default::default(),
+        eq_token: spanned(),
         expr: expr.into_expr().into(),
-        semi_token: Default::default(),
+        semi_token: spanned(),
     }
 }
 
@@ -146,20 +163,23 @@ impl ItemConstBuilder for ItemConst {
 pub fn item_enum(ident: impl IntoIdent) -> ItemEnum {
     ItemEnum {
         attrs: Default::default(),
-        vis: Visibility::Public(Default::default()),
-        enum_token: Default::default(),
+        vis: Visibility::Public(spanned()),
+        enum_token: spanned(),
         ident: ident.into_ident(),
         generics: Default::default(),
-        brace_token: Default::default(),
+        brace_token: spanned(),
         variants: Default::default(),
     }
 }
 
 attrs_builder!(ItemEnum);
+derive_builder!(ItemEnum);
 vis_builder!(ItemEnum);
 generics_builder!(ItemEnum);
 
-pub trait ItemEnumBuilder: AttrsPropsBuilder + VisPropsBuilder + ItemGenericsPropsBuilder {
+pub trait ItemEnumBuilder:
+    AttrsPropsBuilder + DerivePropsBuilder + VisPropsBuilder + ItemGenericsPropsBuilder
+{
     fn new(ident: impl IntoIdent) -> Self;
     fn variant(self, variant: impl Into<Variant>) -> Self;
     fn variants<V: Into<Variant>>(self, variants: impl IntoIterator<Item = V>) -> Self;
@@ -171,13 +191,13 @@ impl ItemEnumBuilder for ItemEnum {
     }
 
     fn variant(mut self, variant: impl Into<Variant>) -> Self {
-        self.variants.push(variant.into());
+        push_punctuated(&mut self.variants, variant.into());
         self
     }
 
     fn variants<V: Into<Variant>>(self, variants: impl IntoIterator<Item = V>) -> Self {
         Self {
-            variants: FromIterator::from_iter(variants.into_iter().map(Into::into)),
+            variants: punctuated(variants.into_iter().map(Into::into)),
             ..self
         }
     }
@@ -187,11 +207,11 @@ pub fn item_extern_crate(ident: impl IntoIdent) -> ItemExternCrate {
     ItemExternCrate {
         attrs: Default::default(),
         vis: Visibility::Inherited,
-        extern_token: Default::default(),
-        crate_token: Default::default(),
+        extern_token: spanned(),
+        crate_token: spanned(),
         ident: ident.into_ident(),
         rename: None,
-        semi_token: Default::default(),
+        semi_token: spanned(),
     }
 }
 
@@ -210,7 +230,7 @@ impl ItemExternCrateBuilder for ItemExternCrate {
 
     fn rename(self, rename: impl IntoIdent) -> Self {
         Self {
-            rename: Some((Default::default(), rename.into_ident())),
+            rename: Some((spanned(), rename.into_ident())),
             ..self
         }
     }
@@ -243,7 +263,7 @@ pub fn item_foreign_mod(abi: impl Into<Abi>) -> ItemForeignMod {
         attrs: Default::default(),
         unsafety: None,
         abi: abi.into(),
-        brace_token: Default::default(),
+        brace_token: spanned(),
         items: Default::default(),
     }
 }
@@ -283,11 +303,11 @@ pub fn item_impl(self_ty: impl IntoType) -> ItemImpl {
         attrs: Default::default(),
         defaultness: None,
         unsafety: None,
-        impl_token: Default::default(),
+        impl_token: spanned(),
         generics: Default::default(),
         trait_: None,
         self_ty: self_ty.into_type().into(),
-        brace_token: Default::default(),
+        brace_token: spanned(),
         items: Default::default(),
     }
 }
@@ -313,11 +333,7 @@ impl ItemImplBuilder for ItemImpl {
 
     fn trait_(self, bang: bool, path: impl IntoPath) -> Self {
         Self {
-            trait_: Some((
-                bang.then(Default::default),
-                path.into_path(),
-                Default::default(),
-            )),
+            trait_: Some((bang.then(spanned), path.into_path(), spanned())),
             ..self
         }
     }
@@ -339,11 +355,12 @@ impl ItemImplBuilder for ItemImpl {
 }
 
 pub fn item_macro(ident: impl IntoIdent, mac: impl Into<Macro>) -> ItemMacro {
+    let mac = mac.into();
     ItemMacro {
         attrs: Default::default(),
         ident: Some(ident.into_ident()),
-        mac: mac.into(),
-        semi_token: None,
+        semi_token: macro_semi(&mac),
+        mac,
     }
 }
 
@@ -364,18 +381,21 @@ pub fn item_mod(ident: impl IntoIdent) -> ItemMod {
         attrs: Default::default(),
         vis: Visibility::Inherited,
         unsafety: None,
-        mod_token: Default::default(),
+        mod_token: spanned(),
         ident: ident.into_ident(),
         content: None,
-        semi: None,
+        semi: Some(spanned()),
     }
 }
 
 attrs_builder!(ItemMod);
+inner_doc_builder!(ItemMod);
 vis_builder!(ItemMod);
 unsafety_builder!(ItemMod);
 
-pub trait ItemModBuilder: AttrsPropsBuilder + VisPropsBuilder + UnsafetyPropsBuilder {
+pub trait ItemModBuilder:
+    AttrsPropsBuilder + InnerDocPropsBuilder + VisPropsBuilder + UnsafetyPropsBuilder
+{
     fn new(ident: impl IntoIdent) -> Self;
     fn content<I: IntoItem>(self, items: impl IntoIterator<Item = I>) -> Self;
 }
@@ -388,9 +408,10 @@ impl ItemModBuilder for ItemMod {
     fn content<I: IntoItem>(self, items: impl IntoIterator<Item = I>) -> Self {
         Self {
             content: Some((
-                Default::default(),
+                spanned(),
                 items.into_iter().map(IntoItem::into_item).collect(),
             )),
+            semi: None,
             ..self
         }
     }
@@ -400,14 +421,14 @@ pub fn item_static(ident: impl IntoIdent, ty: impl IntoType, expr: impl IntoExpr
     ItemStatic {
         attrs: Default::default(),
         vis: Visibility::Inherited,
-        static_token: Default::default(),
+        static_token: spanned(),
         mutability: StaticMutability::None,
         ident: ident.into_ident(),
-        colon_token: Default::default(),
+        colon_token: spanned(),
         ty: ty.into_type().into(),
-        eq_token: Default::default(),
+        eq_token: spanned(),
         expr: expr.into_expr().into(),
-        semi_token: Default::default(),
+        semi_token: spanned(),
     }
 }
 
@@ -427,7 +448,7 @@ impl ItemStaticBuilder for ItemStatic {
     fn mutability(self, mutability: bool) -> Self {
         Self {
             mutability: if mutability {
-                StaticMutability::Mut(Default::default())
+                StaticMutability::Mut(spanned())
             } else {
                 StaticMutability::None
             },
@@ -437,23 +458,25 @@ impl ItemStaticBuilder for ItemStatic {
 }
 
 pub fn item_struct(ident: impl IntoIdent, fields: impl IntoFields) -> ItemStruct {
+    let fields = fields.into_fields();
     ItemStruct {
         attrs: Default::default(),
         vis: Visibility::Inherited,
-        struct_token: Default::default(),
+        struct_token: spanned(),
         ident: ident.into_ident(),
         generics: Default::default(),
-        fields: fields.into_fields(),
-        semi_token: None,
+        semi_token: (!matches!(fields, Fields::Named(_))).then(spanned),
+        fields,
     }
 }
 
 attrs_builder!(ItemStruct);
+derive_builder!(ItemStruct);
 vis_builder!(ItemStruct);
 generics_builder!(ItemStruct);
 
 pub trait ItemStructBuilder:
-    AttrsPropsBuilder + VisPropsBuilder + ItemGenericsPropsBuilder
+    AttrsPropsBuilder + DerivePropsBuilder + VisPropsBuilder + ItemGenericsPropsBuilder
 {
     fn new(ident: impl IntoIdent, fields: impl IntoFields) -> Self;
 }
@@ -471,12 +494,12 @@ pub fn item_trait(ident: impl IntoIdent) -> ItemTrait {
         unsafety: None,
         auto_token: None,
         restriction: None,
-        trait_token: Default::default(),
+        trait_token: spanned(),
         ident: ident.into_ident(),
         generics: Default::default(),
         colon_token: None,
         supertraits: Default::default(),
-        brace_token: Default::default(),
+        brace_token: spanned(),
         items: Default::default(),
     }
 }
@@ -504,25 +527,28 @@ impl ItemTraitBuilder for ItemTrait {
 
     fn auto(self, auto: bool) -> Self {
         Self {
-            auto_token: auto.then(Default::default),
+            auto_token: auto.then(spanned),
             ..self
         }
     }
 
     fn colon_token(self, colon_token: bool) -> Self {
         Self {
-            colon_token: colon_token.then(Default::default),
+            colon_token: colon_token.then(spanned),
             ..self
         }
     }
 
     fn supertraits<S: IntoTypeParamBound>(self, supertraits: impl IntoIterator<Item = S>) -> Self {
+        let supertraits: Punctuated<_, _> = punctuated(
+            supertraits
+                .into_iter()
+                .map(IntoTypeParamBound::into_type_param_bound),
+        );
+
         Self {
-            supertraits: FromIterator::from_iter(
-                supertraits
-                    .into_iter()
-                    .map(IntoTypeParamBound::into_type_param_bound),
-            ),
+            colon_token: (!supertraits.is_empty()).then(spanned),
+            supertraits,
             ..self
         }
     }
@@ -550,16 +576,16 @@ pub fn item_trait_alias<B: IntoTypeParamBound>(
     ItemTraitAlias {
         attrs: Default::default(),
         vis: Visibility::Inherited,
-        trait_token: Default::default(),
+        trait_token: spanned(),
         ident: ident.into_ident(),
         generics: Default::default(),
-        eq_token: Default::default(),
-        bounds: FromIterator::from_iter(
+        eq_token: spanned(),
+        bounds: punctuated(
             bounds
                 .into_iter()
                 .map(IntoTypeParamBound::into_type_param_bound),
         ),
-        semi_token: Default::default(),
+        semi_token: spanned(),
     }
 }
 
@@ -589,12 +615,12 @@ pub fn item_type(ident: impl IntoIdent, ty: impl IntoType) -> ItemType {
     ItemType {
         attrs: Default::default(),
         vis: Visibility::Inherited,
-        type_token: Default::default(),
+        type_token: spanned(),
         ident: ident.into_ident(),
         generics: Default::default(),
-        eq_token: Default::default(),
+        eq_token: spanned(),
         ty: ty.into_type().into(),
-        semi_token: Default::default(),
+        semi_token: spanned(),
     }
 }
 
@@ -616,7 +642,7 @@ pub fn item_union(ident: impl IntoIdent, fields: impl Into<FieldsNamed>) -> Item
     ItemUnion {
         attrs: Default::default(),
         vis: Visibility::Inherited,
-        union_token: Default::default(),
+        union_token: spanned(),
         ident: ident.into_ident(),
         generics: Default::default(),
         fields: fields.into(),
@@ -624,10 +650,13 @@ pub fn item_union(ident: impl IntoIdent, fields: impl Into<FieldsNamed>) -> Item
 }
 
 attrs_builder!(ItemUnion);
+derive_builder!(ItemUnion);
 vis_builder!(ItemUnion);
 generics_builder!(ItemUnion);
 
-pub trait ItemUnionBuilder: AttrsPropsBuilder + VisPropsBuilder + ItemGenericsPropsBuilder {
+pub trait ItemUnionBuilder:
+    AttrsPropsBuilder + DerivePropsBuilder + VisPropsBuilder + ItemGenericsPropsBuilder
+{
     fn new(ident: impl IntoIdent, fields: impl Into<FieldsNamed>) -> Self;
 }
 
@@ -641,10 +670,10 @@ pub fn item_use(tree: impl IntoUseTree) -> ItemUse {
     ItemUse {
         attrs: Default::default(),
         vis: Visibility::Inherited,
-        use_token: Default::default(),
+        use_token: spanned(),
         leading_colon: None,
         tree: tree.into_use_tree(),
-        semi_token: Default::default(),
+        semi_token: spanned(),
     }
 }
 
@@ -663,7 +692,7 @@ impl ItemUseBuilder for ItemUse {
 
     fn leading(self, leading: bool) -> Self {
         Self {
-            leading_colon: leading.then(Default::default),
+            leading_colon: leading.then(spanned),
             ..self
         }
     }
@@ -702,7 +731,7 @@ impl_into_use_tree!(
 pub fn use_path(ident: impl IntoIdent, tree: impl IntoUseTree) -> UsePath {
     UsePath {
         ident: ident.into_ident(),
-        colon2_token: Default::default(),
+        colon2_token: spanned(),
         tree: tree.into_use_tree().into(),
     }
 }
@@ -736,7 +765,7 @@ impl UseNameBuilder for UseName {
 pub fn use_rename(name: impl IntoIdent, rename: impl IntoIdent) -> UseRename {
     UseRename {
         ident: name.into_ident(),
-        as_token: Default::default(),
+        as_token: spanned(),
         rename: rename.into_ident(),
     }
 }
@@ -753,7 +782,7 @@ impl UseRenamBuilder for UseRename {
 
 pub fn use_glob() -> UseGlob {
     UseGlob {
-        star_token: Default::default(),
+        star_token: spanned(),
     }
 }
 
@@ -769,8 +798,8 @@ impl UseGlobBuilder for UseGlob {
 
 pub fn use_group<I: IntoUseTree>(items: impl IntoIterator<Item = I>) -> UseGroup {
     UseGroup {
-        brace_token: Default::default(),
-        items: FromIterator::from_iter(items.into_iter().map(IntoUseTree::into_use_tree)),
+        brace_token: spanned(),
+        items: punctuated(items.into_iter().map(IntoUseTree::into_use_tree)),
     }
 }
 
@@ -819,7 +848,7 @@ pub fn foreign_item_fn(sig: impl Into<Signature>) -> ForeignItemFn {
         attrs: Default::default(),
         vis: Visibility::Inherited,
         sig: sig.into(),
-        semi_token: Default::default(),
+        semi_token: spanned(),
     }
 }
 
@@ -840,12 +869,12 @@ pub fn foreign_item_static(ident: impl IntoIdent, ty: impl IntoType) -> ForeignI
     ForeignItemStatic {
         attrs: Default::default(),
         vis: Visibility::Inherited,
-        static_token: Default::default(),
+        static_token: spanned(),
         mutability: StaticMutability::None,
         ident: ident.into_ident(),
-        colon_token: Default::default(),
+        colon_token: spanned(),
         ty: ty.into_type().into(),
-        semi_token: Default::default(),
+        semi_token: spanned(),
     }
 }
 
@@ -865,7 +894,7 @@ impl ForeignItemStaticBuilder for ForeignItemStatic {
     fn mutability(self, mutability: bool) -> Self {
         Self {
             mutability: if mutability {
-                StaticMutability::Mut(Default::default())
+                StaticMutability::Mut(spanned())
             } else {
                 StaticMutability::None
             },
@@ -878,10 +907,10 @@ pub fn foreign_item_type(ident: impl IntoIdent) -> ForeignItemType {
     ForeignItemType {
         attrs: Default::default(),
         vis: Visibility::Inherited,
-        type_token: Default::default(),
+        type_token: spanned(),
         ident: ident.into_ident(),
         generics: Default::default(),
-        semi_token: Default::default(),
+        semi_token: spanned(),
     }
 }
 
@@ -902,10 +931,11 @@ impl ForeignItemTypeBuilder for ForeignItemType {
 }
 
 pub fn foreign_item_macro(mac: impl Into<Macro>) -> ForeignItemMacro {
+    let mac = mac.into();
     ForeignItemMacro {
         attrs: Default::default(),
-        mac: mac.into(),
-        semi_token: None,
+        semi_token: macro_semi(&mac),
+        mac,
     }
 }
 
@@ -954,13 +984,13 @@ impl_into_trait_item!(
 pub fn trait_item_const(ident: impl IntoIdent, ty: impl IntoType) -> TraitItemConst {
     TraitItemConst {
         attrs: Default::default(),
-        const_token: Default::default(),
+        const_token: spanned(),
         ident: ident.into_ident(),
         generics: Default::default(),
-        colon_token: Default::default(),
+        colon_token: spanned(),
         ty: ty.into_type(),
         default: None,
-        semi_token: Default::default(),
+        semi_token: spanned(),
     }
 }
 
@@ -979,7 +1009,7 @@ impl TraitItemConstBuilder for TraitItemConst {
 
     fn default(self, expr: impl IntoExpr) -> Self {
         Self {
-            default: Some((Default::default(), expr.into_expr())),
+            default: Some((spanned(), expr.into_expr())),
             ..self
         }
     }
@@ -990,7 +1020,7 @@ pub fn trait_item_fn(sig: impl Into<Signature>) -> TraitItemFn {
         attrs: Default::default(),
         sig: sig.into(),
         default: None,
-        semi_token: Default::default(),
+        semi_token: Some(spanned()),
     }
 }
 
@@ -1009,6 +1039,7 @@ impl TraitItemFnBuilder for TraitItemFn {
     fn default(self, block: impl Into<Block>) -> Self {
         Self {
             default: Some(block.into()),
+            semi_token: None,
             ..self
         }
     }
@@ -1017,13 +1048,13 @@ impl TraitItemFnBuilder for TraitItemFn {
 pub fn trait_item_type(ident: impl IntoIdent) -> TraitItemType {
     TraitItemType {
         attrs: Default::default(),
-        type_token: Default::default(),
+        type_token: spanned(),
         ident: ident.into_ident(),
         generics: Default::default(),
         colon_token: None,
         bounds: Default::default(),
         default: None,
-        semi_token: Default::default(),
+        semi_token: spanned(),
     }
 }
 
@@ -1042,8 +1073,8 @@ impl TraitItemTypeBuilder for TraitItemType {
 
     fn bounds<B: IntoTypeParamBound>(self, bounds: impl IntoIterator<Item = B>) -> Self {
         Self {
-            colon_token: Some(Default::default()),
-            bounds: FromIterator::from_iter(
+            colon_token: Some(spanned()),
+            bounds: punctuated(
                 bounds
                     .into_iter()
                     .map(IntoTypeParamBound::into_type_param_bound),
@@ -1054,17 +1085,18 @@ impl TraitItemTypeBuilder for TraitItemType {
 
     fn default(self, ty: impl IntoType) -> Self {
         Self {
-            default: Some((Default::default(), ty.into_type())),
+            default: Some((spanned(), ty.into_type())),
             ..self
         }
     }
 }
 
 pub fn trait_item_macro(mac: impl Into<Macro>) -> TraitItemMacro {
+    let mac = mac.into();
     TraitItemMacro {
         attrs: Default::default(),
-        mac: mac.into(),
-        semi_token: None,
+        semi_token: macro_semi(&mac),
+        mac,
     }
 }
 
@@ -1119,14 +1151,14 @@ pub fn impl_item_const(
         attrs: Default::default(),
         vis: Visibility::Inherited,
         defaultness: None,
-        const_token: Default::default(),
+        const_token: spanned(),
         ident: ident.into_ident(),
         generics: Default::default(),
-        colon_token: Default::default(),
+        colon_token: spanned(),
         ty: ty.into_type(),
-        eq_token: Default::default(),
+        eq_token: spanned(),
         expr: expr.into_expr(),
-        semi_token: Default::default(),
+        semi_token: spanned(),
     }
 }
 
@@ -1175,12 +1207,12 @@ pub fn impl_item_type(ident: impl IntoIdent, ty: impl IntoType) -> ImplItemType
         attrs: Default::default(),
         vis: Visibility::Inherited,
         defaultness: None,
-        type_token: Default::default(),
+        type_token: spanned(),
         ident: ident.into_ident(),
         generics: Default::default(),
-        eq_token: Default::default(),
+        eq_token: spanned(),
         ty: ty.into_type(),
-        semi_token: Default::default(),
+        semi_token: spanned(),
     }
 }
 
@@ -1202,10 +1234,11 @@ impl ImplItemTypeBuilder for ImplItemType {
 }
 
 pub fn impl_item_macro(mac: impl Into<Macro>) -> ImplItemMacro {
+    let mac = mac.into();
     ImplItemMacro {
         attrs: Default::default(),
-        mac: mac.into(),
-        semi_token: None,
+        semi_token: macro_semi(&mac),
+        mac,
     }
 }
 
@@ -1230,11 +1263,11 @@ pub fn signature<I: IntoFnArg>(
         asyncness: None,
         unsafety: None,
         abi: None,
-        fn_token: Default::default(),
+        fn_token: spanned(),
         ident: ident.into_ident(),
         generics: Default::default(),
-        paren_token: Default::default(),
-        inputs: FromIterator::from_iter(inputs.into_iter().map(IntoFnArg::into_fn_arg)),
+        paren_token: spanned(),
+        inputs: punctuated(inputs.into_iter().map(IntoFnArg::into_fn_arg)),
         variadic: None,
         output: ReturnType::Default,
     }
@@ -1260,14 +1293,14 @@ impl SignatureBuilder for Signature {
     }
     fn constness(self, constness: bool) -> Self {
         Self {
-            constness: constness.then(Default::default),
+            constness: constness.then(spanned),
             ..self
         }
     }
 
     fn asyncness(self, asyncness: bool) -> Self {
         Self {
-            asyncness: asyncness.then(Default::default),
+            asyncness: asyncness.then(spanned),
             ..self
         }
     }
@@ -1279,7 +1312,11 @@ impl SignatureBuilder for Signature {
         }
     }
 
-    fn variadic(self, variadic: impl Into<Variadic>) -> Self {
+    fn variadic(mut self, variadic: impl Into<Variadic>) -> Self {
+        if !self.inputs.empty_or_trailing() {
+            self.inputs.push_punct(spanned());
+        }
+
         Self {
             variadic: Some(variadic.into()),
             ..self
@@ -1309,17 +1346,61 @@ impl IntoFnArg for PatType {
     }
 }
 
+impl<T: IntoType> IntoFnArg for (&str, T) {
+    fn into_fn_arg(self) -> FnArg {
+        FnArg::Typed(arg(self.0, self.1))
+    }
+}
+
+pub fn arg(name: impl IntoIdent, ty: impl IntoType) -> PatType {
+    pat_type(pat_ident(name), ty)
+}
+
+pub fn self_value() -> Receiver {
+    receiver()
+}
+
+pub fn self_ref() -> Receiver {
+    receiver().reference(true)
+}
+
+pub fn self_mut() -> Receiver {
+    receiver().reference(true).mutability(true)
+}
+
 pub fn receiver() -> Receiver {
     Receiver {
         attrs: Default::default(),
         reference: None,
         mutability: None,
-        self_token: Default::default(),
-        colon_token: Some(Default::default()),
+        self_token: spanned(),
+        colon_token: None,
         ty: type_path("Self").into_type().into(),
     }
 }
 
+fn receiver_ty(receiver: Receiver) -> Receiver {
+    if receiver.colon_token.is_some() {
+        return receiver;
+    }
+
+    let ty = match &receiver.reference {
+        Some((_, lifetime)) => {
+            let ty = type_reference(type_path("Self")).mutability(receiver.mutability.is_some());
+            match lifetime {
+                Some(lifetime) => ty.lifetime(lifetime.clone()).into_type(),
+                None => ty.into_type(),
+            }
+        }
+        None => type_path("Self").into_type(),
+    };
+
+    Receiver {
+        ty: ty.into(),
+        ..receiver
+    }
+}
+
 attrs_builder!(Receiver);
 
 pub trait ReceiverBuilder: AttrsPropsBuilder {
@@ -1335,29 +1416,29 @@ impl ReceiverBuilder for Receiver {
         receiver()
     }
     fn reference(self, reference: bool) -> Self {
-        Self {
-            reference: reference.then(|| (Default::default(), None)),
+        receiver_ty(Self {
+            reference: reference.then(|| (spanned(), None)),
             ..self
-        }
+        })
     }
 
     fn lifetime(self, lifetime: impl Into<Lifetime>) -> Self {
-        Self {
-            reference: Some((Default::default(), Some(lifetime.into()))),
+        receiver_ty(Self {
+            reference: Some((spanned(), Some(lifetime.into()))),
             ..self
-        }
+        })
     }
 
     fn mutability(self, mutability: bool) -> Self {
-        Self {
-            mutability: mutability.then(Default::default),
+        receiver_ty(Self {
+            mutability: mutability.then(spanned),
             ..self
-        }
+        })
     }
 
     fn ty(self, ty: impl IntoType) -> Self {
         Self {
-            colon_token: Some(Default::default()),
+            colon_token: Some(spanned()),
             ty: ty.into_type().into(),
             ..self
         }
@@ -1368,7 +1449,7 @@ pub fn variadic() -> Variadic {
     Variadic {
         attrs: Default::default(),
         pat: None,
-        dots: Default::default(),
+        dots: spanned(),
         comma: None,
     }
 }
@@ -1386,12 +1467,12 @@ impl VariadicBuilder for Variadic {
     }
     fn pat(self, pat: impl IntoPat) -> Self {
         Self {
-            pat: Some((pat.into_pat().into(), Default::default())),
+            pat: Some((pat.into_pat().into(), spanned())),
             ..self
         }
     }
 }
 
 pub fn static_mutability_mut_variant() -> StaticMutability {
-    StaticMutability::Mut(Default::default())
+    StaticMutability::Mut(spanned())
 }
@@ -1,24 +1,49 @@
+mod accessors;
 mod attr;
+#[cfg(feature = "build")]
+mod build;
+mod builder_pattern;
+mod cfg;
 mod data;
 mod derive;
+mod derive_impl;
+mod dsl;
+mod enum_utils;
 mod expr;
 mod file;
+mod generator;
 mod generics;
 mod ident;
 mod item;
 mod lit;
 mod mac;
+mod macro_rules;
 mod macros;
 mod op;
 mod pat;
 mod path;
+mod precedence;
+#[cfg(feature = "pretty")]
+mod pretty;
 mod restriction;
+#[cfg(feature = "roundtrip")]
+mod roundtrip;
+mod span;
 mod stmt;
+mod trait_impl;
 mod ty;
 
+pub use accessors::{accessors, strip_accessor_attrs, AccessorOptions, Accessors};
 pub use attr::{
-    attr_style_inner, attribute, meta_list, meta_name_value, AttributeBuilder, IntoAttrStyle,
-    IntoMeta, MetaListBuilder, MetaNameValueBuilder,
+    attr_style_inner, attribute, attribute_derive, attribute_doc, attribute_inner_doc, meta_list,
+    meta_name_value, AttributeBuilder, IntoAttrStyle, IntoMeta, MetaListBuilder,
+    MetaNameValueBuilder,
+};
+#[cfg(feature = "build")]
+pub use build::{build_output, BuildOutput};
+pub use builder_pattern::{builder_pattern, missing_field_error, BuilderPattern};
+pub use cfg::{
+    attribute_cfg, attribute_cfg_attr, cfg_all, cfg_any, cfg_feature, cfg_key_value, cfg_not,
 };
 pub use data::{
     field, fields_named, fields_unamed, variant, FieldBuilder, FieldsNamedBuilder,
@@ -28,14 +53,20 @@ pub use derive::{
     data_enum, data_struct, data_union, derive_input, DataEnumBuilder, DataStructBuilder,
     DataUnionBuilder, DeriveInputBuilder, IntoData,
 };
+pub use derive_impl::{
+    derive_clone, derive_debug, derive_default, derive_eq, derive_hash, derive_partial_eq,
+    derive_partial_ord, DeriveImpl,
+};
+pub use dsl::{e, E};
+pub use enum_utils::{enum_utils, unknown_variant_error, Casing, EnumUtils};
 pub use expr::{
     arm, expr_array, expr_assign, expr_async, expr_await, expr_binary, expr_block, expr_break,
     expr_call, expr_cast, expr_closure, expr_const, expr_continue, expr_field, expr_for_loop,
     expr_group, expr_if, expr_index, expr_infer, expr_let, expr_lit, expr_loop, expr_macro,
     expr_match, expr_method_call, expr_paren, expr_path, expr_range, expr_reference, expr_repeat,
     expr_return, expr_struct, expr_try, expr_try_block, expr_tuple, expr_unary, expr_unsafe,
-    expr_while, expr_yield, field_value, index, label, range_limits_closed_variant,
-    range_limits_half_open_variant, ArmBuilder, ExprArrayBuilder, ExprAssignBuilder,
+    expr_while, expr_yield, field_value, if_chain, index, label, range_limits_closed_variant,
+    range_limits_half_open_variant, try_expr, ArmBuilder, ExprArrayBuilder, ExprAssignBuilder,
     ExprAsyncBuilder, ExprAwaitBuilder, ExprBinaryBuilder, ExprBlockBuilder, ExprBreakBuilder,
     ExprCallBuilder, ExprCastBuilder, ExprClosureBuilder, ExprConstBuilder, ExprContinueBuilder,
     ExprFieldBuilder, ExprForLoopBuilder, ExprGroupBuilder, ExprIfBuilder, ExprIndexBuilder,
@@ -43,8 +74,8 @@ pub use expr::{
     ExprMatchBuilder, ExprMethodCallBuilder, ExprParenBuilder, ExprPathBuilder, ExprRangeBuilder,
     ExprReferenceBuilder, ExprRepeatBuilder, ExprReturnBuilder, ExprStructBuilder,
     ExprTryBlockBuilder, ExprTryBuilder, ExprTupleBuilder, ExprUnaryBuilder, ExprUnsafeBuilder,
-    ExprWhileBuilder, ExprYieldBuilder, FieldValueBuilder, IndexBuilder, IntoExpr, IntoRangeLimits,
-    LabelBuilder,
+    ExprWhileBuilder, ExprYieldBuilder, FieldValueBuilder, IfChain, IndexBuilder, IntoArm,
+    IntoExpr, IntoRangeLimits, LabelBuilder,
 };
 pub use file::{file, FileBuilder};
 pub use generics::{
@@ -54,28 +85,46 @@ pub use generics::{
     LifetimeParamBuilder, PredicateLifetimeBuilder, PredicateTypeBuilder, TraitBoundBuilder,
     TypeParamBuilder, WhereClauseBuilder,
 };
-pub use ident::IntoIdent;
+pub use ident::{
+    ident_raw, ident_safe, ident_screaming_snake_case, ident_snake_case, ident_upper_camel_case,
+    try_ident, try_ident_escaped, IntoIdent, TryIntoIdent,
+};
 pub use item::{
-    foreign_item_fn, foreign_item_macro, foreign_item_static, foreign_item_type, impl_item_const,
-    impl_item_fn, impl_item_macro, impl_item_type, item_const, item_enum, item_extern_crate,
-    item_fn, item_foreign_mod, item_impl, item_macro, item_mod, item_static, item_struct,
-    item_trait, item_trait_alias, item_type, item_union, item_use, receiver, signature,
-    static_mutability_mut_variant, trait_item_const, trait_item_fn, trait_item_macro,
-    trait_item_type, use_glob, use_group, use_name, use_path, use_rename, variadic,
-    ForeignItemFnBuilder, ForeignItemMacroBuilder, ForeignItemStaticBuilder,
-    ForeignItemTypeBuilder, ImplItemConstBuilder, ImplItemFnBuilder, ImplItemMacroBuilder,
-    ImplItemTypeBuilder, IntoFnArg, IntoForeignItem, IntoImplItem, IntoItem, IntoTraitItem,
-    IntoUseTree, ItemConstBuilder, ItemEnumBuilder, ItemExternCrateBuilder, ItemFnBuilder,
-    ItemForeignModBuilder, ItemImplBuilder, ItemMacroBuilder, ItemModBuilder, ItemStaticBuilder,
-    ItemStructBuilder, ItemTraitAliasBuilder, ItemTraitBuilder, ItemTypeBuilder, ItemUnionBuilder,
-    ItemUseBuilder, ReceiverBuilder, SignatureBuilder, TraitItemConstBuilder, TraitItemFnBuilder,
-    TraitItemMacroBuilder, TraitItemTypeBuilder, UseGlobBuilder, UseGroupBuilder, UseNameBuilder,
+    arg, foreign_item_fn, foreign_item_macro, foreign_item_static, foreign_item_type,
+    impl_item_const, impl_item_fn, impl_item_macro, impl_item_type, item_const, item_enum,
+    item_extern_crate, item_fn, item_foreign_mod, item_impl, item_macro, item_mod, item_static,
+    item_struct, item_trait, item_trait_alias, item_type, item_union, item_use, receiver, self_mut,
+    self_ref, self_value, signature, static_mutability_mut_variant, trait_item_const,
+    trait_item_fn, trait_item_macro, trait_item_type, use_glob, use_group, use_name, use_path,
+    use_rename, variadic, DefaultnessPropsBuilder, ForeignItemFnBuilder, ForeignItemMacroBuilder,
+    ForeignItemStaticBuilder, ForeignItemTypeBuilder, ImplItemConstBuilder, ImplItemFnBuilder,
+    ImplItemMacroBuilder, ImplItemTypeBuilder, IntoFnArg, IntoForeignItem, IntoImplItem, IntoItem,
+    IntoTraitItem, IntoUseTree, ItemConstBuilder, ItemEnumBuilder, ItemExternCrateBuilder,
+    ItemFnBuilder, ItemForeignModBuilder, ItemGenericsPropsBuilder, ItemImplBuilder,
+    ItemMacroBuilder, ItemModBuilder, ItemStaticBuilder, ItemStructBuilder, ItemTraitAliasBuilder,
+    ItemTraitBuilder, ItemTypeBuilder, ItemUnionBuilder, ItemUseBuilder, ReceiverBuilder,
+    SignatureBuilder, TraitItemConstBuilder, TraitItemFnBuilder, TraitItemMacroBuilder,
+    TraitItemTypeBuilder, UnsafetyPropsBuilder, UseGlobBuilder, UseGroupBuilder, UseNameBuilder,
     UsePathBuilder, UseRenamBuilder, VariadicBuilder,
 };
-pub use lit::{lit_bool, lit_byte, lit_byte_str, lit_char, lit_float, lit_int, lit_str, IntoLit};
+pub use lit::{
+    lit_bool, lit_byte, lit_byte_str, lit_char, lit_f32, lit_f64, lit_float, lit_float_unsuffixed,
+    lit_i128, lit_i16, lit_i32, lit_i64, lit_i8, lit_int, lit_int_unsuffixed, lit_isize, lit_str,
+    lit_u128, lit_u16, lit_u32, lit_u64, lit_u8, lit_usize, IntoLit,
+};
 pub use mac::{
-    macro_delimiter_brace_variant, macro_delimiter_bracket_variant, macro_delimiter_paren_variant,
-    r#macro, IntoMacroDelimiter, MacroBuilder,
+    assert_eq_macro, format_macro, macro_call, macro_delimiter_brace_variant,
+    macro_delimiter_bracket_variant, macro_delimiter_paren_variant, panic_macro, println_macro,
+    r#macro, todo_macro, vec_macro, IntoMacroDelimiter, MacroBuilder,
+};
+pub use macro_rules::{
+    macro_fragment, macro_repetition, macro_repetition_sep, macro_rules, macro_var,
+    MacroRulesBuilder, RepetitionOp,
+};
+pub use macros::{
+    AttrsPropsBuilder, CfgPropsBuilder, DerivePropsBuilder, DocPropsBuilder, InnerDocPropsBuilder,
+    LabelPropsBuilder, MutabilityPropsBuilder, OutputPropsBuilder, QSelfPropsBuilder,
+    VisPropsBuilder,
 };
 pub use op::{
     bin_op_add_assign_variant, bin_op_add_variant, bin_op_and_variant,
@@ -89,32 +138,41 @@ pub use op::{
     un_op_not, IntoBinOp, IntoUnOp,
 };
 pub use pat::{
-    field_pat, pat_ident, pat_or, pat_paren, pat_reference, pat_rest, pat_slice, pat_struct,
-    pat_tuple, pat_tuple_struct, pat_type, pat_wild, FieldPatBuilder, IntoPat, PatIdentBuilder,
-    PatOrBuilder, PatParenBuilder, PatReferenceBuilder, PatRestBuilder, PatSliceBuilder,
-    PatStructBuilder, PatTupleBuilder, PatTupleStructBuilder, PatTypeBuilder, PatWildBuilder,
+    field_pat, pat_const, pat_ident, pat_lit, pat_macro, pat_or, pat_paren, pat_path, pat_range,
+    pat_reference, pat_rest, pat_slice, pat_struct, pat_tuple, pat_tuple_struct, pat_type,
+    pat_wild, FieldPatBuilder, IntoPat, PatConstBuilder, PatIdentBuilder, PatLitBuilder,
+    PatMacroBuilder, PatOrBuilder, PatParenBuilder, PatPathBuilder, PatRangeBuilder,
+    PatReferenceBuilder, PatRestBuilder, PatSliceBuilder, PatStructBuilder, PatTupleBuilder,
+    PatTupleStructBuilder, PatTypeBuilder, PatWildBuilder,
 };
 pub use path::{
     angle_bracketed_generic_arguments, assoc_const, assoc_type, constraint,
-    parenthesized_generic_arguments, path, path_segment, q_self,
+    parenthesized_generic_arguments, path, path_segment, q_self, try_path,
     AngleBracketedGenericArgumentsBuilder, AssocConstBuilder, AssocTypeBuilder, ConstraintBuilder,
     IntoGenericArgument, IntoPath, IntoPathArguments, ParenthesizedGenericArgumentsBuilder,
-    PathBuilder, PathSeqmentBuilder, QSelfBuilder,
+    PathBuilder, PathGenericsBuilder, PathSeqmentBuilder, QSelfBuilder,
 };
+pub use precedence::{parenthesize, parenthesize_block, parenthesize_file, parenthesize_item};
+#[cfg(feature = "pretty")]
+pub use pretty::{pretty_expr, pretty_file, pretty_item, Pretty};
 pub use restriction::{
     field_mutability_none_variant, vis_restricted, visibility_public_variant, IntoVisibility,
     VisRestrictedBuilder,
 };
+#[cfg(feature = "roundtrip")]
+pub use roundtrip::{assert_roundtrip, try_roundtrip};
+pub use span::{current_span, with_span};
 pub use stmt::{
-    block, local, local_init, stmt_macro, BlockBuilder, IntoStmt, LocalBuilder, LocalInitBuilder,
-    StmtMacroBuilder,
+    block, local, local_init, stmt_expr, stmt_macro, stmt_semi, BlockBuilder, IntoStmt,
+    LocalBuilder, LocalInitBuilder, SemiBuilder, StmtMacroBuilder,
 };
+pub use trait_impl::{trait_impl, TraitImpl};
 pub use ty::{
-    abi, bare_fn_arg, bare_variadic, type_array, type_bare_fn, type_group, type_impl_trait,
-    type_infer, type_macro, type_never, type_paren, type_path, type_ptr_const, type_ptr_mut,
-    type_reference, type_slice, type_trait_object, type_tuple, AbiBuilder, BareFnArgBuilder,
-    BareVariadicBuilder, IntoType, TypeArrayBuilder, TypeBareFnBuilder, TypeGroupBuilder,
-    TypeImplTraitBuilder, TypeInferBuilder, TypeMacroBuilder, TypeNeverBuilder, TypeParenBuilder,
-    TypePathBuilder, TypePtrBuilder, TypeReferenceBuilder, TypeSliceBuilder,
+    abi, bare_fn_arg, bare_variadic, try_type, type_array, type_bare_fn, type_group,
+    type_impl_trait, type_infer, type_macro, type_never, type_paren, type_path, type_ptr_const,
+    type_ptr_mut, type_reference, type_slice, type_trait_object, type_tuple, AbiBuilder,
+    BareFnArgBuilder, BareVariadicBuilder, IntoType, TypeArrayBuilder, TypeBareFnBuilder,
+    TypeGroupBuilder, TypeImplTraitBuilder, TypeInferBuilder, TypeMacroBuilder, TypeNeverBuilder,
+    TypeParenBuilder, TypePathBuilder, TypePtrBuilder, TypeReferenceBuilder, TypeSliceBuilder,
     TypeTraitObjectBuilder, TypeTupleBuilder,
 };
@@ -1,5 +1,9 @@
-use proc_macro2::Span;
-use syn::{Lit, LitBool, LitByte, LitByteStr, LitChar, LitFloat, LitInt, LitStr};
+use crate::{
+    current_span, expr_lit, expr_path, expr_unary, pat_lit, path, path_segment, un_op_neg,
+    IntoExpr, IntoPat,
+};
+use proc_macro2::Literal;
+use syn::{Expr, Lit, LitBool, LitByte, LitByteStr, LitChar, LitFloat, LitInt, LitStr, Pat};
 
 pub trait IntoLit {
     fn into_lit(self) -> Lit;
@@ -12,7 +16,7 @@ impl IntoLit for Lit {
 }
 
 pub fn lit_str(value: &str) -> LitStr {
-    LitStr::new(value, Span::call_site())
+    LitStr::new(value, current_span())
 }
 
 impl IntoLit for LitStr {
@@ -22,7 +26,7 @@ impl IntoLit for LitStr {
 }
 
 pub fn lit_byte_str(value: &[u8]) -> LitByteStr {
-    LitByteStr::new(value, Span::call_site())
+    LitByteStr::new(value, current_span())
 }
 
 impl IntoLit for LitByteStr {
@@ -32,7 +36,7 @@ impl IntoLit for LitByteStr {
 }
 
 pub fn lit_byte(value: u8) -> LitByte {
-    LitByte::new(value, Span::call_site())
+    LitByte::new(value, current_span())
 }
 
 impl IntoLit for LitByte {
@@ -42,7 +46,7 @@ impl IntoLit for LitByte {
 }
 
 pub fn lit_char(value: char) -> LitChar {
-    LitChar::new(value, Span::call_site())
+    LitChar::new(value, current_span())
 }
 
 impl IntoLit for LitChar {
@@ -52,7 +56,7 @@ impl IntoLit for LitChar {
 }
 
 pub fn lit_int(value: &str) -> LitInt {
-    LitInt::new(value, Span::call_site())
+    LitInt::new(value, current_span())
 }
 
 impl IntoLit for LitInt {
@@ -62,7 +66,7 @@ impl IntoLit for LitInt {
 }
 
 pub fn lit_float(value: &str) -> LitFloat {
-    LitFloat::new(value, Span::call_site())
+    LitFloat::new(value, current_span())
 }
 
 impl IntoLit for LitFloat {
@@ -72,7 +76,7 @@ impl IntoLit for LitFloat {
 }
 
 pub fn lit_bool(value: bool) -> LitBool {
-    LitBool::new(value, Span::call_site())
+    LitBool::new(value, current_span())
 }
 
 impl IntoLit for LitBool {
@@ -80,3 +84,204 @@ impl IntoLit for LitBool {
         Lit::Bool(self)
     }
 }
+
+impl IntoLit for &str {
+    fn into_lit(self) -> Lit {
+        Lit::Str(lit_str(self))
+    }
+}
+
+impl IntoLit for &[u8] {
+    fn into_lit(self) -> Lit {
+        Lit::ByteStr(lit_byte_str(self))
+    }
+}
+
+impl IntoLit for char {
+    fn into_lit(self) -> Lit {
+        Lit::Char(lit_char(self))
+    }
+}
+
+impl IntoLit for bool {
+    fn into_lit(self) -> Lit {
+        Lit::Bool(lit_bool(self))
+    }
+}
+
+macro_rules! impl_into_expr_lit {
+    ($($type:ty,)*) => {
+        $(
+            impl IntoExpr for $type {
+                fn into_expr(self) -> Expr {
+                    Expr::Lit(expr_lit(self))
+                }
+            }
+
+            impl IntoPat for $type {
+                fn into_pat(self) -> Pat {
+                    Pat::Lit(pat_lit(self))
+                }
+            }
+        )*
+    };
+}
+
+impl_into_expr_lit!(&str, &[u8], char, bool,);
+
+fn spanned_literal(mut literal: Literal) -> Literal {
+    literal.set_span(current_span());
+    literal
+}
+
+fn negate(lit: impl IntoLit, negative: bool) -> Expr {
+    if negative {
+        Expr::Unary(expr_unary(un_op_neg(), expr_lit(lit)))
+    } else {
+        Expr::Lit(expr_lit(lit))
+    }
+}
+
+fn constant(ty: &str, name: &str) -> Expr {
+    Expr::Path(expr_path(path([path_segment(ty), path_segment(name)])))
+}
+
+fn float_constant(ty: &str, nan: bool, infinite: bool, negative: bool) -> Option<Expr> {
+    match (nan, infinite, negative) {
+        (true, _, _) => Some(constant(ty, "NAN")),
+        (_, true, false) => Some(constant(ty, "INFINITY")),
+        (_, true, true) => Some(constant(ty, "NEG_INFINITY")),
+        _ => None,
+    }
+}
+
+macro_rules! lit_unsigned {
+    ($($name:ident($type:ident) => $literal:ident,)*) => {
+        $(
+            pub fn $name(value: $type) -> LitInt {
+                LitInt::from(spanned_literal(Literal::$literal(value)))
+            }
+
+            impl IntoLit for $type {
+                fn into_lit(self) -> Lit {
+                    Lit::Int($name(self))
+                }
+            }
+
+            impl IntoExpr for $type {
+                fn into_expr(self) -> Expr {
+                    Expr::Lit(expr_lit(self))
+                }
+            }
+
+            impl IntoPat for $type {
+                fn into_pat(self) -> Pat {
+                    Pat::Lit(pat_lit(self))
+                }
+            }
+        )*
+    };
+}
+
+lit_unsigned!(
+    lit_u8(u8) => u8_suffixed,
+    lit_u16(u16) => u16_suffixed,
+    lit_u32(u32) => u32_suffixed,
+    lit_u64(u64) => u64_suffixed,
+    lit_u128(u128) => u128_suffixed,
+    lit_usize(usize) => usize_suffixed,
+);
+
+macro_rules! lit_signed {
+    ($($name:ident($type:ident) => $literal:ident,)*) => {
+        $(
+            pub fn $name(value: $type) -> Expr {
+                if value == $type::MIN {
+                    return constant(stringify!($type), "MIN");
+                }
+                negate(value, value < 0)
+            }
+
+            impl IntoLit for $type {
+                fn into_lit(self) -> Lit {
+                    let literal = match self.checked_abs() {
+                        Some(magnitude) => Literal::$literal(magnitude),
+                        None => Literal::u128_unsuffixed(self.unsigned_abs() as u128),
+                    };
+                    Lit::Int(LitInt::from(spanned_literal(literal)))
+                }
+            }
+
+            impl IntoExpr for $type {
+                fn into_expr(self) -> Expr {
+                    $name(self)
+                }
+            }
+
+            impl IntoPat for $type {
+                fn into_pat(self) -> Pat {
+                    let lit = LitInt::from(spanned_literal(Literal::$literal(self)));
+                    Pat::Lit(pat_lit(lit))
+                }
+            }
+        )*
+    };
+}
+
+lit_signed!(
+    lit_i8(i8) => i8_suffixed,
+    lit_i16(i16) => i16_suffixed,
+    lit_i32(i32) => i32_suffixed,
+    lit_i64(i64) => i64_suffixed,
+    lit_i128(i128) => i128_suffixed,
+    lit_isize(isize) => isize_suffixed,
+);
+
+macro_rules! lit_floating {
+    ($($name:ident($type:ident) => $literal:ident,)*) => {
+        $(
+            pub fn $name(value: $type) -> Expr {
+                let negative = value.is_sign_negative();
+                float_constant(stringify!($type), value.is_nan(), value.is_infinite(), negative)
+                    .unwrap_or_else(|| negate(value, negative))
+            }
+
+            impl IntoLit for $type {
+                fn into_lit(self) -> Lit {
+                    assert!(
+                        self.is_finite(),
+                        "`{}` has no literal form, convert it with `IntoExpr`",
+                        self
+                    );
+                    Lit::Float(LitFloat::from(spanned_literal(Literal::$literal(self.abs()))))
+                }
+            }
+
+            impl IntoExpr for $type {
+                fn into_expr(self) -> Expr {
+                    $name(self)
+                }
+            }
+        )*
+    };
+}
+
+lit_floating!(
+    lit_f32(f32) => f32_suffixed,
+    lit_f64(f64) => f64_suffixed,
+);
+
+pub fn lit_int_unsuffixed(value: i128) -> Expr {
+    let lit = LitInt::from(spanned_literal(Literal::u128_unsuffixed(
+        value.unsigned_abs(),
+    )));
+    negate(lit, value < 0)
+}
+
+pub fn lit_float_unsuffixed(value: f64) -> Expr {
+    let negative = value.is_sign_negative();
+    float_constant("f64", value.is_nan(), value.is_infinite(), negative).unwrap_or_else(|| {
+        let lit = LitFloat::from(spanned_literal(Literal::f64_unsuffixed(value.abs())));
+        negate(lit, negative)
+    })
+}
@@ -1,16 +1,22 @@
 use proc_macro2::TokenStream;
+use quote::ToTokens;
 use syn::{
-    token::{Brace, Bracket, Paren},
-    Macro, MacroDelimiter,
+    punctuated::Punctuated,
+    token::{Brace, Bracket, Comma, Paren, Semi},
+    Expr, Macro, MacroDelimiter,
 };
 
-use crate::IntoPath;
+use crate::{
+    expr_lit,
+    span::{punctuated, spanned},
+    IntoExpr, IntoPath,
+};
 
 pub fn r#macro(path: impl IntoPath, tokens: impl Into<TokenStream>) -> Macro {
     Macro {
         path: path.into_path(),
-        bang_token: Default::default(),
-        delimiter: MacroDelimiter::Paren(Default::default()),
+        bang_token: spanned(),
+        delimiter: MacroDelimiter::Paren(spanned()),
         tokens: tokens.into(),
     }
 }
@@ -25,16 +31,59 @@ impl MacroBuilder for Macro {
     }
 }
 
+pub fn macro_call<A: IntoExpr>(path: impl IntoPath, args: impl IntoIterator<Item = A>) -> Macro {
+    let args: Punctuated<Expr, Comma> = punctuated(args.into_iter().map(IntoExpr::into_expr));
+
+    r#macro(path, args.into_token_stream())
+}
+
+fn format_args<A: IntoExpr>(fmt: &str, args: impl IntoIterator<Item = A>) -> Vec<Expr> {
+    std::iter::once(expr_lit(fmt).into_expr())
+        .chain(args.into_iter().map(IntoExpr::into_expr))
+        .collect()
+}
+
+pub fn vec_macro<E: IntoExpr>(elems: impl IntoIterator<Item = E>) -> Macro {
+    Macro {
+        delimiter: macro_delimiter_bracket_variant(),
+        ..macro_call("vec", elems)
+    }
+}
+
+pub fn format_macro<A: IntoExpr>(fmt: &str, args: impl IntoIterator<Item = A>) -> Macro {
+    macro_call("format", format_args(fmt, args))
+}
+
+pub fn println_macro<A: IntoExpr>(fmt: &str, args: impl IntoIterator<Item = A>) -> Macro {
+    macro_call("println", format_args(fmt, args))
+}
+
+pub fn panic_macro<A: IntoExpr>(fmt: &str, args: impl IntoIterator<Item = A>) -> Macro {
+    macro_call("panic", format_args(fmt, args))
+}
+
+pub fn assert_eq_macro(left: impl IntoExpr, right: impl IntoExpr) -> Macro {
+    macro_call("assert_eq", [left.into_expr(), right.into_expr()])
+}
+
+pub fn todo_macro() -> Macro {
+    macro_call::<Expr>("todo", [])
+}
+
+pub(crate) fn macro_semi(mac: &Macro) -> Option<Semi> {
+    (!matches!(mac.delimiter, MacroDelimiter::Brace(_))).then(spanned)
+}
+
 pub fn macro_delimiter_paren_variant() -> MacroDelimiter {
-    MacroDelimiter::Paren(Default::default())
+    MacroDelimiter::Paren(spanned())
 }
 
 pub fn macro_delimiter_brace_variant() -> MacroDelimiter {
-    MacroDelimiter::Brace(Default::default())
+    MacroDelimiter::Brace(spanned())
 }
 
 pub fn macro_delimiter_bracket_variant() -> MacroDelimiter {
-    MacroDelimiter::Bracket(Default::default())
+    MacroDelimiter::Bracket(spanned())
 }
 
 pub trait IntoMacroDelimiter {
@@ -0,0 +1,144 @@
+use proc_macro2::{Delimiter, Group, Punct, Spacing, TokenStream, TokenTree};
+use quote::{quote_spanned, ToTokens, TokenStreamExt};
+use syn::{ItemMacro, Meta};
+
+use crate::{
+    attribute, item_macro, macro_delimiter_brace_variant, r#macro, span::current_span,
+    AttrsPropsBuilder, IntoIdent,
+};
+
+pub fn macro_rules(ident: impl IntoIdent) -> ItemMacro {
+    item_macro(
+        ident,
+        syn::Macro {
+            delimiter: macro_delimiter_brace_variant(),
+            ..r#macro("macro_rules", TokenStream::new())
+        },
+    )
+}
+
+pub trait MacroRulesBuilder: AttrsPropsBuilder {
+    fn new(ident: impl IntoIdent) -> Self;
+    fn rule<M: ToTokens>(
+        self,
+        matcher: impl IntoIterator<Item = M>,
+        transcriber: impl ToTokens,
+    ) -> Self;
+    fn export(self, export: bool) -> Self;
+}
+
+impl MacroRulesBuilder for ItemMacro {
+    fn new(ident: impl IntoIdent) -> Self {
+        macro_rules(ident)
+    }
+
+    fn rule<M: ToTokens>(
+        mut self,
+        matcher: impl IntoIterator<Item = M>,
+        transcriber: impl ToTokens,
+    ) -> Self {
+        let matcher: TokenStream = matcher
+            .into_iter()
+            .map(ToTokens::into_token_stream)
+            .collect();
+
+        self.mac.tokens.extend(quote_spanned!(current_span()=>
+            (#matcher) => { #transcriber };
+        ));
+        self
+    }
+
+    fn export(mut self, export: bool) -> Self {
+        let is_export = |meta: &Meta| meta.path().is_ident("macro_export");
+        self.attrs.retain(|attr| !is_export(&attr.meta));
+        if export {
+            self.attrs.push(attribute("macro_export"));
+        }
+        self
+    }
+}
+
+fn dollar() -> TokenTree {
+    let mut dollar = Punct::new('$', Spacing::Alone);
+    dollar.set_span(current_span());
+    dollar.into()
+}
+
+pub fn macro_var(name: impl IntoIdent) -> TokenStream {
+    let mut tokens = TokenStream::new();
+    tokens.append(dollar());
+    tokens.append(name.into_ident());
+    tokens
+}
+
+pub fn macro_fragment(name: impl IntoIdent, specifier: impl IntoIdent) -> TokenStream {
+    let mut tokens = macro_var(name);
+    tokens.extend(quote_spanned!(current_span()=> :));
+    tokens.append(specifier.into_ident());
+    tokens
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum RepetitionOp {
+    ZeroOrMore,
+    OneOrMore,
+    ZeroOrOne,
+}
+
+impl RepetitionOp {
+    fn as_char(self) -> char {
+        match self {
+            Self::ZeroOrMore => '*',
+            Self::OneOrMore => '+',
+            Self::ZeroOrOne => '?',
+        }
+    }
+}
+
+pub fn macro_repetition<T: ToTokens>(
+    tokens: impl IntoIterator<Item = T>,
+    op: RepetitionOp,
+) -> TokenStream {
+    repetition(tokens, TokenStream::new(), op)
+}
+
+pub fn macro_repetition_sep<T: ToTokens>(
+    tokens: impl IntoIterator<Item = T>,
+    separator: impl ToTokens,
+    op: RepetitionOp,
+) -> syn::Result<TokenStream> {
+    if op == RepetitionOp::ZeroOrOne {
+        return Err(syn::Error::new(
+            current_span(),
+            "`?` repetitions cannot have a separator",
+        ));
+    }
+
+    Ok(repetition(tokens, separator.into_token_stream(), op))
+}
+
+fn repetition<T: ToTokens>(
+    tokens: impl IntoIterator<Item = T>,
+    separator: TokenStream,
+    op: RepetitionOp,
+) -> TokenStream {
+    let span = current_span();
+    let mut group = Group::new(
+        Delimiter::Parenthesis,
+        tokens
+            .into_iter()
+            .map(ToTokens::into_token_stream)
+            .collect(),
+    );
+    group.set_span(span);
+
+    let mut op = Punct::new(op.as_char(), Spacing::Alone);
+    op.set_span(span);
+
+    let mut repetition = TokenStream::new();
+    repetition.append(dollar());
+    repetition.append(group);
+    repetition.extend(separator);
+    repetition.append(op);
+    repetition
+}
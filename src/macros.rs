@@ -1,6 +1,9 @@
 use syn::{Attribute, Label, QSelf};
 
-use crate::{IntoType, IntoVisibility};
+use crate::{
+    attr::doc_lines, attribute_cfg, attribute_cfg_attr, attribute_doc, IntoMeta, IntoPath,
+    IntoType, IntoVisibility,
+};
 
 pub trait AttrsPropsBuilder {
     fn attr(self, attr: impl Into<Attribute>) -> Self;
@@ -9,7 +12,7 @@ pub trait AttrsPropsBuilder {
 
 #[macro_export]
 macro_rules! attrs_builder {
-    ($($name:ident),+) => {
+    (inner $($name:ident),+) => {
         $(
             impl $crate::macros::AttrsPropsBuilder for $name {
                 fn attr(mut self, attr: impl Into<syn::Attribute>) -> Self {
@@ -25,6 +28,90 @@ macro_rules! attrs_builder {
                 }
             }
         )*
+    };
+    ($($name:ident),+) => {
+        $crate::attrs_builder!(inner $($name),+);
+        $(
+            impl $crate::macros::DocPropsBuilder for $name {}
+        )*
+    };
+}
+
+pub trait DocPropsBuilder: AttrsPropsBuilder + Sized {
+    fn doc(self, doc: &str) -> Self {
+        doc_lines(doc).map(attribute_doc).fold(self, Self::attr)
+    }
+
+    fn docs<D: AsRef<str>>(self, docs: impl IntoIterator<Item = D>) -> Self {
+        docs.into_iter()
+            .fold(self, |this, doc| this.doc(doc.as_ref()))
+    }
+}
+
+pub trait CfgPropsBuilder {
+    fn cfg(self, predicate: impl IntoMeta) -> Self;
+    fn cfg_attr<A: IntoMeta>(
+        self,
+        predicate: impl IntoMeta,
+        attrs: impl IntoIterator<Item = A>,
+    ) -> Self;
+}
+
+impl<T: AttrsPropsBuilder> CfgPropsBuilder for T {
+    fn cfg(self, predicate: impl IntoMeta) -> Self {
+        self.attr(attribute_cfg(predicate))
+    }
+
+    fn cfg_attr<A: IntoMeta>(
+        self,
+        predicate: impl IntoMeta,
+        attrs: impl IntoIterator<Item = A>,
+    ) -> Self {
+        self.attr(attribute_cfg_attr(predicate, attrs))
+    }
+}
+
+pub trait InnerDocPropsBuilder {
+    fn inner_doc(self, doc: &str) -> Self;
+    fn inner_docs<D: AsRef<str>>(self, docs: impl IntoIterator<Item = D>) -> Self;
+}
+
+#[macro_export]
+macro_rules! inner_doc_builder {
+    ($($name:ident),+) => {
+        $(
+            impl $crate::macros::InnerDocPropsBuilder for $name {
+                fn inner_doc(mut self, doc: &str) -> Self {
+                    self.attrs.extend($crate::attr::doc_lines(doc).map($crate::attribute_inner_doc));
+                    self
+                }
+
+                fn inner_docs<D: AsRef<str>>(self, docs: impl IntoIterator<Item = D>) -> Self {
+                    docs.into_iter().fold(self, |this, doc| this.inner_doc(doc.as_ref()))
+                }
+            }
+        )*
+    }
+}
+
+pub trait DerivePropsBuilder {
+    fn derive<P: IntoPath>(self, paths: impl IntoIterator<Item = P>) -> Self;
+}
+
+#[macro_export]
+macro_rules! derive_builder {
+    ($($name:ident),+) => {
+        $(
+            impl $crate::macros::DerivePropsBuilder for $name {
+                fn derive<P: $crate::IntoPath>(mut self, paths: impl IntoIterator<Item = P>) -> Self {
+                    $crate::attr::merge_derive(
+                        &mut self.attrs,
+                        paths.into_iter().map($crate::IntoPath::into_path),
+                    );
+                    self
+                }
+            }
+        )*
     }
 }
 
@@ -39,7 +126,7 @@ macro_rules! mutability_builder {
             impl $crate::macros::MutabilityPropsBuilder for $name {
                 fn mutability(self, mutability: bool) -> Self {
                     Self {
-                        mutability: mutability.then(Default::default),
+                        mutability: mutability.then($crate::span::spanned),
                         ..self
                     }
                 }
@@ -57,9 +144,14 @@ macro_rules! qself_builder {
     ($($name:ident),+) => {
         $(
             impl $crate::macros::QSelfPropsBuilder for $name {
-                fn qself(self, qself: impl Into<syn::QSelf>) -> Self {
+                fn qself(mut self, qself: impl Into<syn::QSelf>) -> Self {
+                    let qself = qself.into();
+                    if qself.position == 0 {
+                        self.path.leading_colon = Some($crate::span::spanned());
+                    }
+
                     Self {
-                        qself: Some(qself.into()),
+                        qself: Some(qself),
                         ..self
                     }
                 }
@@ -116,7 +208,7 @@ macro_rules! output_builder {
             impl $crate::macros::OutputPropsBuilder for $name {
                 fn output(self, ty: impl $crate::IntoType) -> Self {
                     Self {
-                        output: syn::ReturnType::Type(Default::default(), ty.into_type().into()),
+                        output: syn::ReturnType::Type($crate::span::spanned(), ty.into_type().into()),
                         ..self
                     }
                 }
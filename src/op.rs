@@ -1,115 +1,116 @@
+use crate::span::spanned;
 use syn::{BinOp, UnOp};
 
 pub fn bin_op_add_variant() -> BinOp {
-    BinOp::Add(Default::default())
+    BinOp::Add(spanned())
 }
 
 pub fn bin_op_sub_variant() -> BinOp {
-    BinOp::Sub(Default::default())
+    BinOp::Sub(spanned())
 }
 
 pub fn bin_op_mul_variant() -> BinOp {
-    BinOp::Mul(Default::default())
+    BinOp::Mul(spanned())
 }
 
 pub fn bin_op_div_variant() -> BinOp {
-    BinOp::Div(Default::default())
+    BinOp::Div(spanned())
 }
 
 pub fn bin_op_rem_variant() -> BinOp {
-    BinOp::Rem(Default::default())
+    BinOp::Rem(spanned())
 }
 
 pub fn bin_op_and_variant() -> BinOp {
-    BinOp::And(Default::default())
+    BinOp::And(spanned())
 }
 
 pub fn bin_op_or_variant() -> BinOp {
-    BinOp::Or(Default::default())
+    BinOp::Or(spanned())
 }
 
 pub fn bin_op_bit_xor_variant() -> BinOp {
-    BinOp::BitXor(Default::default())
+    BinOp::BitXor(spanned())
 }
 
 pub fn bin_op_bit_and_variant() -> BinOp {
-    BinOp::BitAnd(Default::default())
+    BinOp::BitAnd(spanned())
 }
 
 pub fn bin_op_bit_or_variant() -> BinOp {
-    BinOp::BitOr(Default::default())
+    BinOp::BitOr(spanned())
 }
 
 pub fn bin_op_shl_variant() -> BinOp {
-    BinOp::Shl(Default::default())
+    BinOp::Shl(spanned())
 }
 
 pub fn bin_op_shr_variant() -> BinOp {
-    BinOp::Shr(Default::default())
+    BinOp::Shr(spanned())
 }
 
 pub fn bin_op_eq_variant() -> BinOp {
-    BinOp::Eq(Default::default())
+    BinOp::Eq(spanned())
 }
 
 pub fn bin_op_lt_variant() -> BinOp {
-    BinOp::Lt(Default::default())
+    BinOp::Lt(spanned())
 }
 
 pub fn bin_op_le_variant() -> BinOp {
-    BinOp::Le(Default::default())
+    BinOp::Le(spanned())
 }
 
 pub fn bin_op_ne_variant() -> BinOp {
-    BinOp::Ne(Default::default())
+    BinOp::Ne(spanned())
 }
 
 pub fn bin_op_ge_variant() -> BinOp {
-    BinOp::Ge(Default::default())
+    BinOp::Ge(spanned())
 }
 
 pub fn bin_op_gt_variant() -> BinOp {
-    BinOp::Gt(Default::default())
+    BinOp::Gt(spanned())
 }
 
 pub fn bin_op_add_assign_variant() -> BinOp {
-    BinOp::AddAssign(Default::default())
+    BinOp::AddAssign(spanned())
 }
 
 pub fn bin_op_sub_assign_variant() -> BinOp {
-    BinOp::SubAssign(Default::default())
+    BinOp::SubAssign(spanned())
 }
 
 pub fn bin_op_mul_assign_variant() -> BinOp {
-    BinOp::MulAssign(Default::default())
+    BinOp::MulAssign(spanned())
 }
 
 pub fn bin_op_div_assign_variant() -> BinOp {
-    BinOp::DivAssign(Default::default())
+    BinOp::DivAssign(spanned())
 }
 
 pub fn bin_op_rem_assign_variant() -> BinOp {
-    BinOp::RemAssign(Default::default())
+    BinOp::RemAssign(spanned())
 }
 
 pub fn bin_op_bit_xor_assign_variant() -> BinOp {
-    BinOp::BitXorAssign(Default::default())
+    BinOp::BitXorAssign(spanned())
 }
 
 pub fn bin_op_bit_and_assign_variant() -> BinOp {
-    BinOp::BitAndAssign(Default::default())
+    BinOp::BitAndAssign(spanned())
 }
 
 pub fn bin_op_bit_or_assign_variant() -> BinOp {
-    BinOp::BitOrAssign(Default::default())
+    BinOp::BitOrAssign(spanned())
 }
 
 pub fn bin_op_shl_assign_variant() -> BinOp {
-    BinOp::ShlAssign(Default::default())
+    BinOp::ShlAssign(spanned())
 }
 
 pub fn bin_op_shr_assign_variant() -> BinOp {
-    BinOp::ShrAssign(Default::default())
+    BinOp::ShrAssign(spanned())
 }
 
 pub trait IntoBinOp {
@@ -166,15 +167,15 @@ impl_bin_op!(
 );
 
 pub fn un_op_deref() -> UnOp {
-    UnOp::Deref(Default::default())
+    UnOp::Deref(spanned())
 }
 
 pub fn un_op_not() -> UnOp {
-    UnOp::Not(Default::default())
+    UnOp::Not(spanned())
 }
 
 pub fn un_op_neg() -> UnOp {
-    UnOp::Neg(Default::default())
+    UnOp::Neg(spanned())
 }
 
 pub trait IntoUnOp {
@@ -1,13 +1,21 @@
 use crate::{
-    attrs_builder,
+    attrs_builder, expr_const, expr_lit, expr_macro, expr_path, expr_range,
     macros::{AttrsPropsBuilder, MutabilityPropsBuilder, QSelfPropsBuilder},
-    mutability_builder, qself_builder, IntoIdent, IntoPath, IntoType,
+    mutability_builder, qself_builder,
+    span::{punctuated, spanned},
+    IntoIdent, IntoLit, IntoPath, IntoRangeLimits, IntoType,
 };
-use proc_macro2::TokenStream;
+use proc_macro2::{Literal, TokenStream};
 use syn::{
-    FieldPat, Member, Pat, PatConst, PatIdent, PatLit, PatMacro, PatOr, PatParen, PatPath,
-    PatRange, PatReference, PatRest, PatSlice, PatStruct, PatTuple, PatTupleStruct, PatType,
-    PatWild,
+    punctuated::Punctuated, Block, Expr, ExprLit, ExprUnary, FieldPat, Lit, LitFloat, LitInt,
+    Macro, Member, Pat, PatConst, PatIdent, PatLit, PatMacro, PatOr, PatParen, PatPath, PatRange,
+    PatReference, PatRest, PatSlice, PatStruct, PatTuple, PatTupleStruct, PatType, PatWild, UnOp,
+};
+
+pub use crate::{
+    ExprConstBuilder as PatConstBuilder, ExprLitBuilder as PatLitBuilder,
+    ExprMacroBuilder as PatMacroBuilder, ExprPathBuilder as PatPathBuilder,
+    ExprRangeBuilder as PatRangeBuilder,
 };
 
 pub trait IntoPat {
@@ -40,7 +48,6 @@ impl_into_pat!(
     Or(PatOr),
     Paren(PatParen),
     Path(PatPath),
-    Range(PatRange),
     Reference(PatReference),
     Rest(PatRest),
     Slice(PatSlice),
@@ -52,6 +59,51 @@ impl_into_pat!(
     Wild(PatWild),
 );
 
+impl IntoPat for PatRange {
+    fn into_pat(self) -> Pat {
+        Pat::Range(PatRange {
+            start: self.start.map(range_bound),
+            end: self.end.map(range_bound),
+            ..self
+        })
+    }
+}
+
+fn range_bound(bound: Box<Expr>) -> Box<Expr> {
+    let Expr::Unary(ExprUnary {
+        op: UnOp::Neg(_),
+        expr,
+        ..
+    }) = &*bound
+    else {
+        return bound;
+    };
+    let lit = match &**expr {
+        Expr::Lit(ExprLit {
+            lit: Lit::Int(lit), ..
+        }) => Lit::Int(LitInt::from(negative(lit.token()))),
+        Expr::Lit(ExprLit {
+            lit: Lit::Float(lit),
+            ..
+        }) => Lit::Float(LitFloat::from(negative(lit.token()))),
+        _ => return bound,
+    };
+
+    Box::new(Expr::Lit(expr_lit(lit)))
+}
+
+fn negative(literal: Literal) -> Literal {
+    let mut negative: Literal = format!("-{literal}")
+        .parse()
+        .expect("a negated numeric literal is a literal");
+    negative.set_span(literal.span());
+    negative
+}
+
+pub fn pat_const(block: impl Into<Block>) -> PatConst {
+    expr_const(block)
+}
+
 pub fn pat_ident(ident: impl IntoIdent) -> PatIdent {
     PatIdent {
         attrs: Default::default(),
@@ -78,24 +130,32 @@ impl PatIdentBuilder for PatIdent {
 
     fn by_ref(self, by_ref: bool) -> Self {
         Self {
-            by_ref: by_ref.then(Default::default),
+            by_ref: by_ref.then(spanned),
             ..self
         }
     }
 
     fn subpat(self, subpat: impl IntoPat) -> Self {
         Self {
-            subpat: Some((Default::default(), subpat.into_pat().into())),
+            subpat: Some((spanned(), subpat.into_pat().into())),
             ..self
         }
     }
 }
 
+pub fn pat_lit(lit: impl IntoLit) -> PatLit {
+    expr_lit(lit)
+}
+
+pub fn pat_macro(mac: impl Into<Macro>) -> PatMacro {
+    expr_macro(mac)
+}
+
 pub fn pat_or<C: IntoPat>(cases: impl IntoIterator<Item = C>) -> PatOr {
     PatOr {
         attrs: Default::default(),
         leading_vert: None,
-        cases: FromIterator::from_iter(cases.into_iter().map(IntoPat::into_pat)),
+        cases: punctuated(cases.into_iter().map(IntoPat::into_pat)),
     }
 }
 
@@ -114,7 +174,7 @@ impl PatOrBuilder for PatOr {
 pub fn pat_paren(pat: impl IntoPat) -> PatParen {
     PatParen {
         attrs: Default::default(),
-        paren_token: Default::default(),
+        paren_token: spanned(),
         pat: pat.into_pat().into(),
     }
 }
@@ -131,10 +191,18 @@ impl PatParenBuilder for PatParen {
     }
 }
 
+pub fn pat_path(path: impl IntoPath) -> PatPath {
+    expr_path(path)
+}
+
+pub fn pat_range(limits: impl IntoRangeLimits) -> PatRange {
+    expr_range(limits)
+}
+
 pub fn pat_reference(pat: impl IntoPat) -> PatReference {
     PatReference {
         attrs: Default::default(),
-        and_token: Default::default(),
+        and_token: spanned(),
         mutability: None,
         pat: pat.into_pat().into(),
     }
@@ -156,7 +224,7 @@ impl PatReferenceBuilder for PatReference {
 pub fn pat_rest() -> PatRest {
     PatRest {
         attrs: Default::default(),
-        dot2_token: Default::default(),
+        dot2_token: spanned(),
     }
 }
 
@@ -175,8 +243,8 @@ impl PatRestBuilder for PatRest {
 pub fn pat_slice<P: IntoPat>(elems: impl IntoIterator<Item = P>) -> PatSlice {
     PatSlice {
         attrs: Default::default(),
-        bracket_token: Default::default(),
-        elems: FromIterator::from_iter(elems.into_iter().map(IntoPat::into_pat)),
+        bracket_token: spanned(),
+        elems: punctuated(elems.into_iter().map(IntoPat::into_pat)),
     }
 }
 
@@ -200,8 +268,8 @@ pub fn pat_struct<F: Into<FieldPat>>(
         attrs: Default::default(),
         qself: None,
         path: path.into_path(),
-        brace_token: Default::default(),
-        fields: FromIterator::from_iter(fields.into_iter().map(Into::into)),
+        brace_token: spanned(),
+        fields: punctuated(fields.into_iter().map(Into::into)),
         rest: None,
     }
 }
@@ -218,7 +286,11 @@ impl PatStructBuilder for PatStruct {
     fn new<F: Into<FieldPat>>(path: impl IntoPath, fields: impl IntoIterator<Item = F>) -> Self {
         pat_struct(path, fields)
     }
-    fn rest(self, rest: PatRest) -> Self {
+    fn rest(mut self, rest: PatRest) -> Self {
+        if !self.fields.empty_or_trailing() {
+            self.fields.push_punct(spanned());
+        }
+
         Self {
             rest: Some(rest),
             ..self
@@ -227,10 +299,15 @@ impl PatStructBuilder for PatStruct {
 }
 
 pub fn pat_tuple<E: IntoPat>(elems: impl IntoIterator<Item = E>) -> PatTuple {
+    let mut elems: Punctuated<_, _> = punctuated(elems.into_iter().map(IntoPat::into_pat));
+    if elems.len() == 1 {
+        elems.push_punct(spanned());
+    }
+
     PatTuple {
         attrs: Default::default(),
-        paren_token: Default::default(),
-        elems: FromIterator::from_iter(elems.into_iter().map(IntoPat::into_pat)),
+        paren_token: spanned(),
+        elems,
     }
 }
 
@@ -254,8 +331,8 @@ pub fn pat_tuple_struct<E: IntoPat>(
         attrs: Default::default(),
         qself: None,
         path: path.into_path(),
-        paren_token: Default::default(),
-        elems: FromIterator::from_iter(elems.into_iter().map(IntoPat::into_pat)),
+        paren_token: spanned(),
+        elems: punctuated(elems.into_iter().map(IntoPat::into_pat)),
     }
 }
 
@@ -276,7 +353,7 @@ pub fn pat_type(pat: impl IntoPat, ty: impl IntoType) -> PatType {
     PatType {
         attrs: Default::default(),
         pat: pat.into_pat().into(),
-        colon_token: Default::default(),
+        colon_token: spanned(),
         ty: ty.into_type().into(),
     }
 }
@@ -296,7 +373,7 @@ impl PatTypeBuilder for PatType {
 pub fn pat_wild() -> PatWild {
     PatWild {
         attrs: Default::default(),
-        underscore_token: Default::default(),
+        underscore_token: spanned(),
     }
 }
 
@@ -316,7 +393,7 @@ pub fn field_pat(member: impl Into<Member>, pat: impl IntoPat) -> FieldPat {
     FieldPat {
         attrs: Default::default(),
         member: member.into(),
-        colon_token: None,
+        colon_token: Some(spanned()),
         pat: pat.into_pat().into(),
     }
 }
@@ -335,7 +412,7 @@ impl FieldPatBuilder for FieldPat {
 
     fn colon_token(self, colon_token: bool) -> Self {
         Self {
-            colon_token: colon_token.then(Default::default),
+            colon_token: colon_token.then(spanned),
             ..self
         }
     }
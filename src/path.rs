@@ -1,12 +1,14 @@
 use crate::{
-    macros::OutputPropsBuilder, output_builder, IntoExpr, IntoIdent, IntoMeta, IntoType,
-    IntoTypeParamBound,
+    macros::OutputPropsBuilder,
+    output_builder,
+    span::{parse_spanned, punctuated, spanned},
+    IntoExpr, IntoIdent, IntoMeta, IntoType, IntoTypeParamBound,
 };
 use proc_macro2::Ident;
 use syn::{
     AngleBracketedGenericArguments, AssocConst, AssocType, Constraint, Expr, GenericArgument,
     Lifetime, Meta, ParenthesizedGenericArguments, Path, PathArguments, PathSegment, QSelf,
-    ReturnType, Type,
+    ReturnType, TraitBound, TraitBoundModifier, Type,
 };
 
 pub trait PathGenericsBuilder {
@@ -31,7 +33,7 @@ macro_rules! generics_builder {
 pub fn path<S: Into<PathSegment>>(segments: impl IntoIterator<Item = S>) -> Path {
     Path {
         leading_colon: None,
-        segments: FromIterator::from_iter(segments.into_iter().map(Into::into)),
+        segments: punctuated(segments.into_iter().map(Into::into)),
     }
 }
 
@@ -41,6 +43,12 @@ impl IntoMeta for Path {
     }
 }
 
+impl IntoMeta for &str {
+    fn into_meta(self) -> syn::Meta {
+        Meta::Path(self.into_path())
+    }
+}
+
 pub trait IntoPath {
     fn into_path(self) -> Path;
 }
@@ -59,10 +67,22 @@ impl IntoPath for Ident {
 
 impl IntoPath for &str {
     fn into_path(self) -> Path {
-        Path::from(self.into_ident())
+        try_path(self).unwrap_or_else(|err| panic!("invalid path `{self}`: {err}"))
     }
 }
 
+pub fn try_path(path: &str) -> syn::Result<Path> {
+    parse_spanned(path).or_else(|err| match parse_spanned(path) {
+        Ok(TraitBound {
+            paren_token: None,
+            modifier: TraitBoundModifier::None,
+            lifetimes: None,
+            path,
+        }) => Ok(path),
+        _ => Err(err),
+    })
+}
+
 pub trait PathBuilder {
     fn new<S: Into<PathSegment>>(segments: impl IntoIterator<Item = S>) -> Self;
     fn leading_colon(self, leading_colon: bool) -> Self;
@@ -75,7 +95,7 @@ impl PathBuilder for Path {
 
     fn leading_colon(self, leading_colon: bool) -> Self {
         Self {
-            leading_colon: leading_colon.then(Default::default),
+            leading_colon: leading_colon.then(spanned),
             ..self
         }
     }
@@ -167,12 +187,12 @@ pub fn angle_bracketed_generic_arguments<A: IntoGenericArgument>(
 ) -> AngleBracketedGenericArguments {
     AngleBracketedGenericArguments {
         colon2_token: None,
-        lt_token: Default::default(),
-        args: FromIterator::from_iter(
+        lt_token: spanned(),
+        args: punctuated(
             args.into_iter()
                 .map(IntoGenericArgument::into_generic_argument),
         ),
-        gt_token: Default::default(),
+        gt_token: spanned(),
     }
 }
 
@@ -194,7 +214,7 @@ impl AngleBracketedGenericArgumentsBuilder for AngleBracketedGenericArguments {
 
     fn colon2_token(self, colon2_token: bool) -> Self {
         Self {
-            colon2_token: colon2_token.then(Default::default),
+            colon2_token: colon2_token.then(spanned),
             ..self
         }
     }
@@ -204,7 +224,7 @@ pub fn assoc_type(ident: impl IntoIdent, ty: impl IntoType) -> AssocType {
     AssocType {
         ident: ident.into_ident(),
         generics: None,
-        eq_token: Default::default(),
+        eq_token: spanned(),
         ty: ty.into_type(),
     }
 }
@@ -225,7 +245,7 @@ pub fn assoc_const(ident: impl IntoIdent, value: impl IntoExpr) -> AssocConst {
     AssocConst {
         ident: ident.into_ident(),
         generics: None,
-        eq_token: Default::default(),
+        eq_token: spanned(),
         value: value.into_expr(),
     }
 }
@@ -249,8 +269,8 @@ pub fn constraint<B: IntoTypeParamBound>(
     Constraint {
         ident: ident.into_ident(),
         generics: None,
-        colon_token: Default::default(),
-        bounds: FromIterator::from_iter(
+        colon_token: spanned(),
+        bounds: punctuated(
             bounds
                 .into_iter()
                 .map(IntoTypeParamBound::into_type_param_bound),
@@ -280,8 +300,8 @@ pub fn parenthesized_generic_arguments<I: IntoType>(
     inputs: impl IntoIterator<Item = I>,
 ) -> ParenthesizedGenericArguments {
     ParenthesizedGenericArguments {
-        paren_token: Default::default(),
-        inputs: FromIterator::from_iter(inputs.into_iter().map(IntoType::into_type)),
+        paren_token: spanned(),
+        inputs: punctuated(inputs.into_iter().map(IntoType::into_type)),
         output: ReturnType::Default,
     }
 }
@@ -306,11 +326,11 @@ impl IntoPathArguments for ParenthesizedGenericArguments {
 
 pub fn q_self(ty: impl IntoType, position: impl Into<usize>) -> QSelf {
     QSelf {
-        lt_token: Default::default(),
+        lt_token: spanned(),
         ty: ty.into_type().into(),
         position: position.into(),
         as_token: None,
-        gt_token: Default::default(),
+        gt_token: spanned(),
     }
 }
 
@@ -327,7 +347,7 @@ impl QSelfBuilder for QSelf {
 
     fn as_token(self, as_token: bool) -> Self {
         Self {
-            as_token: as_token.then(Default::default),
+            as_token: as_token.then(spanned),
             ..self
         }
     }
@@ -0,0 +1,239 @@
+use crate::{expr_paren, IntoExpr, IntoItem};
+use proc_macro2::TokenStream;
+use syn::{
+    visit_mut::{self, VisitMut},
+    BinOp, Block, Expr, File, Item,
+};
+
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+enum Precedence {
+    Jump,
+    Assign,
+    Range,
+    Or,
+    And,
+    Compare,
+    BitOr,
+    BitXor,
+    BitAnd,
+    Shift,
+    Sum,
+    Product,
+    Cast,
+    Prefix,
+    Unambiguous,
+}
+
+fn bin_op_precedence(op: &BinOp) -> Precedence {
+    match op {
+        BinOp::Mul(_) | BinOp::Div(_) | BinOp::Rem(_) => Precedence::Product,
+        BinOp::Add(_) | BinOp::Sub(_) => Precedence::Sum,
+        BinOp::Shl(_) | BinOp::Shr(_) => Precedence::Shift,
+        BinOp::BitAnd(_) => Precedence::BitAnd,
+        BinOp::BitXor(_) => Precedence::BitXor,
+        BinOp::BitOr(_) => Precedence::BitOr,
+        BinOp::Eq(_) | BinOp::Lt(_) | BinOp::Le(_) | BinOp::Ne(_) | BinOp::Ge(_) | BinOp::Gt(_) => {
+            Precedence::Compare
+        }
+        BinOp::And(_) => Precedence::And,
+        BinOp::Or(_) => Precedence::Or,
+        _ => Precedence::Assign,
+    }
+}
+
+fn precedence(expr: &Expr) -> Precedence {
+    match expr {
+        Expr::Binary(binary) => bin_op_precedence(&binary.op),
+        Expr::Assign(_) => Precedence::Assign,
+        Expr::Range(_) => Precedence::Range,
+        Expr::Cast(_) => Precedence::Cast,
+        Expr::Unary(_) | Expr::Reference(_) => Precedence::Prefix,
+        Expr::Closure(_) | Expr::Return(_) | Expr::Break(_) | Expr::Yield(_) | Expr::Let(_) => {
+            Precedence::Jump
+        }
+        _ => Precedence::Unambiguous,
+    }
+}
+
+fn paren_if(expr: &mut Expr, paren: bool) {
+    if paren && !matches!(expr, Expr::Let(_)) {
+        let inner = std::mem::replace(expr, Expr::Verbatim(TokenStream::new()));
+        *expr = expr_paren(inner).into_expr();
+    }
+}
+
+fn ends_with_cast(expr: &Expr) -> bool {
+    match expr {
+        Expr::Cast(_) => true,
+        Expr::Binary(binary) => ends_with_cast(&binary.right),
+        Expr::Assign(assign) => ends_with_cast(&assign.right),
+        Expr::Unary(unary) => ends_with_cast(&unary.expr),
+        Expr::Reference(reference) => ends_with_cast(&reference.expr),
+        Expr::Range(range) => range.end.as_deref().is_some_and(ends_with_cast),
+        _ => false,
+    }
+}
+
+fn has_exposed_struct(expr: &Expr) -> bool {
+    match expr {
+        Expr::Struct(_) => true,
+        Expr::Binary(binary) => {
+            has_exposed_struct(&binary.left) || has_exposed_struct(&binary.right)
+        }
+        Expr::Assign(assign) => {
+            has_exposed_struct(&assign.left) || has_exposed_struct(&assign.right)
+        }
+        Expr::Range(range) => {
+            range.start.as_deref().is_some_and(has_exposed_struct)
+                || range.end.as_deref().is_some_and(has_exposed_struct)
+        }
+        Expr::Cast(cast) => has_exposed_struct(&cast.expr),
+        Expr::Unary(unary) => has_exposed_struct(&unary.expr),
+        Expr::Reference(reference) => has_exposed_struct(&reference.expr),
+        Expr::Field(field) => has_exposed_struct(&field.base),
+        Expr::MethodCall(call) => has_exposed_struct(&call.receiver),
+        Expr::Call(call) => has_exposed_struct(&call.func),
+        Expr::Index(index) => has_exposed_struct(&index.expr),
+        Expr::Try(try_) => has_exposed_struct(&try_.expr),
+        Expr::Await(await_) => has_exposed_struct(&await_.base),
+        _ => false,
+    }
+}
+
+fn parenthesize_condition(cond: &mut Expr) {
+    match cond {
+        Expr::Let(let_) => {
+            let paren = has_exposed_struct(&let_.expr);
+            paren_if(&mut let_.expr, paren);
+        }
+        Expr::Binary(binary) if matches!(binary.op, BinOp::And(_)) => {
+            parenthesize_condition(&mut binary.left);
+            parenthesize_condition(&mut binary.right);
+        }
+        _ => {
+            let paren = has_exposed_struct(cond);
+            paren_if(cond, paren);
+        }
+    }
+}
+
+pub(crate) fn parenthesize_operands(expr: &mut Expr) {
+    let parent = precedence(expr);
+
+    match expr {
+        Expr::Binary(binary) if parent == Precedence::Assign => {
+            let paren = precedence(&binary.left) <= Precedence::Assign;
+            paren_if(&mut binary.left, paren);
+        }
+        Expr::Binary(binary) => {
+            let left = precedence(&binary.left);
+            let cast_ambiguity = matches!(binary.op, BinOp::Lt(_) | BinOp::Le(_) | BinOp::Shl(_))
+                && ends_with_cast(&binary.left);
+            let paren =
+                left < parent || left == parent && parent == Precedence::Compare || cast_ambiguity;
+            paren_if(&mut binary.left, paren);
+
+            let paren = precedence(&binary.right) <= parent;
+            paren_if(&mut binary.right, paren);
+        }
+        Expr::Assign(assign) => {
+            let paren = precedence(&assign.left) <= Precedence::Assign;
+            paren_if(&mut assign.left, paren);
+        }
+        Expr::Range(range) => {
+            if let Some(start) = &mut range.start {
+                let paren = precedence(start) <= Precedence::Range;
+                paren_if(start, paren);
+            }
+            if let Some(end) = &mut range.end {
+                let paren = precedence(end) <= Precedence::Range;
+                paren_if(end, paren);
+            }
+        }
+        Expr::Cast(cast) => {
+            let paren = precedence(&cast.expr) < Precedence::Cast;
+            paren_if(&mut cast.expr, paren);
+        }
+        Expr::Unary(unary) => {
+            let paren = precedence(&unary.expr) < Precedence::Prefix;
+            paren_if(&mut unary.expr, paren);
+        }
+        Expr::Reference(reference) => {
+            let paren = precedence(&reference.expr) < Precedence::Prefix;
+            paren_if(&mut reference.expr, paren);
+        }
+        Expr::Field(field) => {
+            let paren = precedence(&field.base) < Precedence::Unambiguous;
+            paren_if(&mut field.base, paren);
+        }
+        Expr::MethodCall(call) => {
+            let paren = precedence(&call.receiver) < Precedence::Unambiguous;
+            paren_if(&mut call.receiver, paren);
+        }
+        Expr::Call(call) => {
+            let paren = precedence(&call.func) < Precedence::Unambiguous
+                || matches!(*call.func, Expr::Field(_));
+            paren_if(&mut call.func, paren);
+        }
+        Expr::Index(index) => {
+            let paren = precedence(&index.expr) < Precedence::Unambiguous;
+            paren_if(&mut index.expr, paren);
+        }
+        Expr::Try(try_) => {
+            let paren = precedence(&try_.expr) < Precedence::Unambiguous;
+            paren_if(&mut try_.expr, paren);
+        }
+        Expr::Await(await_) => {
+            let paren = precedence(&await_.base) < Precedence::Unambiguous;
+            paren_if(&mut await_.base, paren);
+        }
+        Expr::Let(let_) => {
+            let paren = precedence(&let_.expr) <= Precedence::And;
+            paren_if(&mut let_.expr, paren);
+        }
+        Expr::If(if_) => parenthesize_condition(&mut if_.cond),
+        Expr::While(while_) => parenthesize_condition(&mut while_.cond),
+        Expr::Match(match_) => {
+            let paren = has_exposed_struct(&match_.expr);
+            paren_if(&mut match_.expr, paren);
+        }
+        Expr::ForLoop(for_loop) => {
+            let paren = has_exposed_struct(&for_loop.expr);
+            paren_if(&mut for_loop.expr, paren);
+        }
+        _ => {}
+    }
+}
+
+struct Parenthesizer;
+
+impl VisitMut for Parenthesizer {
+    fn visit_expr_mut(&mut self, expr: &mut Expr) {
+        visit_mut::visit_expr_mut(self, expr);
+        parenthesize_operands(expr);
+    }
+}
+
+pub fn parenthesize(expr: impl IntoExpr) -> Expr {
+    let mut expr = expr.into_expr();
+    Parenthesizer.visit_expr_mut(&mut expr);
+    expr
+}
+
+pub fn parenthesize_block(block: impl Into<Block>) -> Block {
+    let mut block = block.into();
+    Parenthesizer.visit_block_mut(&mut block);
+    block
+}
+
+pub fn parenthesize_item(item: impl IntoItem) -> Item {
+    let mut item = item.into_item();
+    Parenthesizer.visit_item_mut(&mut item);
+    item
+}
+
+pub fn parenthesize_file(file: impl Into<File>) -> File {
+    let mut file = file.into();
+    Parenthesizer.visit_file_mut(&mut file);
+    file
+}
@@ -0,0 +1,86 @@
+use crate::{file, item_const, type_tuple, IntoExpr, IntoItem};
+use std::{fmt, fs, io, path::Path};
+use syn::{File, Type};
+
+#[derive(Clone)]
+pub struct Pretty {
+    header: Option<String>,
+    source: String,
+}
+
+pub fn pretty_file(file: impl Into<File>) -> Pretty {
+    Pretty {
+        header: None,
+        source: prettyplease::unparse(&file.into()),
+    }
+}
+
+pub fn pretty_item(item: impl IntoItem) -> Pretty {
+    pretty_file(file([item.into_item()]))
+}
+
+pub fn pretty_expr(expr: impl IntoExpr) -> Pretty {
+    const PREFIX: &str = "const _: () =";
+
+    let source = prettyplease::unparse(&file([item_const("_", type_tuple::<Type>([]), expr)]));
+    let source = source
+        .strip_prefix(PREFIX)
+        .and_then(|source| source.strip_suffix(";\n"))
+        .map_or(source.as_str(), str::trim_start);
+
+    Pretty {
+        header: None,
+        source: format!("{source}\n"),
+    }
+}
+
+impl Pretty {
+    pub fn header(self, header: &str) -> Self {
+        let header = header
+            .lines()
+            .map(|line| {
+                if line.starts_with("//") {
+                    format!("{line}\n")
+                } else if line.is_empty() {
+                    "//\n".to_string()
+                } else {
+                    format!("// {line}\n")
+                }
+            })
+            .collect::<String>();
+
+        Self {
+            header: (!header.is_empty()).then_some(header),
+            ..self
+        }
+    }
+
+    pub fn write_file(&self, path: impl AsRef<Path>) -> io::Result<bool> {
+        let path = path.as_ref();
+        let contents = self.to_string();
+
+        if fs::read(path).is_ok_and(|existing| existing == contents.as_bytes()) {
+            return Ok(false);
+        }
+
+        if let Some(parent) = path
+            .parent()
+            .filter(|parent| !parent.as_os_str().is_empty())
+        {
+            fs::create_dir_all(parent)?;
+        }
+
+        fs::write(path, contents)?;
+        Ok(true)
+    }
+}
+
+impl fmt::Display for Pretty {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some(header) = &self.header {
+            writeln!(f, "{header}")?;
+        }
+
+        f.write_str(&self.source)
+    }
+}
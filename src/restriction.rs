@@ -1,4 +1,4 @@
-use crate::IntoPath;
+use crate::{span::spanned, IntoPath};
 use syn::{token, FieldMutability, VisRestricted, Visibility};
 
 pub trait IntoVisibility {
@@ -24,7 +24,7 @@ impl IntoVisibility for VisRestricted {
 }
 
 pub fn visibility_public_variant() -> Visibility {
-    Visibility::Public(Default::default())
+    Visibility::Public(spanned())
 }
 
 const RESTRICTED_CRATE_IDENTS: &[&str] = &["self", "super", "crate"];
@@ -36,9 +36,9 @@ pub fn vis_restricted(path: impl IntoPath) -> VisRestricted {
         .is_some_and(|ident| !RESTRICTED_CRATE_IDENTS.contains(&ident.to_string().as_str()));
 
     VisRestricted {
-        pub_token: Default::default(),
-        paren_token: Default::default(),
-        in_token: custom_path.then(Default::default),
+        pub_token: spanned(),
+        paren_token: spanned(),
+        in_token: custom_path.then(spanned),
         path: path.into(),
     }
 }
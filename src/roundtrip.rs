@@ -0,0 +1,30 @@
+use quote::ToTokens;
+use std::fmt::Debug;
+use syn::parse::Parse;
+
+pub fn try_roundtrip<T>(node: &T) -> Result<(), String>
+where
+    T: Parse + ToTokens + PartialEq + Debug,
+{
+    let tokens = node.to_token_stream();
+    let reparsed: T = syn::parse2(tokens.clone())
+        .map_err(|err| format!("`{tokens}` does not parse back: {err}"))?;
+
+    if reparsed != *node {
+        return Err(format!(
+            "`{tokens}` parses back as a different node\n built: {node:#?}\nparsed: {reparsed:#?}"
+        ));
+    }
+
+    Ok(())
+}
+
+#[track_caller]
+pub fn assert_roundtrip<T>(node: &T)
+where
+    T: Parse + ToTokens + PartialEq + Debug,
+{
+    if let Err(err) = try_roundtrip(node) {
+        panic!("{err}");
+    }
+}
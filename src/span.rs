@@ -0,0 +1,93 @@
+use proc_macro2::{Group, Span, TokenStream, TokenTree};
+use std::cell::Cell;
+use syn::{parse::Parse, punctuated::Punctuated, token};
+
+thread_local! {
+    static CURRENT_SPAN: Cell<Option<Span>> = const { Cell::new(None) };
+}
+
+pub fn current_span() -> Span {
+    CURRENT_SPAN.with(Cell::get).unwrap_or_else(Span::call_site)
+}
+
+pub fn with_span<R>(span: Span, f: impl FnOnce() -> R) -> R {
+    struct Restore(Option<Span>);
+
+    impl Drop for Restore {
+        fn drop(&mut self) {
+            CURRENT_SPAN.with(|current| current.set(self.0));
+        }
+    }
+
+    let _restore = Restore(CURRENT_SPAN.with(|current| current.replace(Some(span))));
+    f()
+}
+
+pub fn respan(tokens: TokenStream, span: Span) -> TokenStream {
+    tokens
+        .into_iter()
+        .map(|tree| match tree {
+            TokenTree::Group(group) => {
+                let mut group = Group::new(group.delimiter(), respan(group.stream(), span));
+                group.set_span(span);
+                TokenTree::Group(group)
+            }
+            mut tree => {
+                tree.set_span(span);
+                tree
+            }
+        })
+        .collect()
+}
+
+pub fn parse_spanned<T: Parse>(source: &str) -> syn::Result<T> {
+    syn::parse2(respan(source.parse()?, current_span()))
+}
+
+pub trait SpannedToken {
+    fn from_span(span: Span) -> Self;
+}
+
+pub fn spanned<T: SpannedToken>() -> T {
+    T::from_span(current_span())
+}
+
+pub(crate) fn punctuated<T, P: SpannedToken>(
+    values: impl IntoIterator<Item = T>,
+) -> Punctuated<T, P> {
+    let mut punctuated = Punctuated::new();
+    for value in values {
+        push_punctuated(&mut punctuated, value);
+    }
+    punctuated
+}
+
+pub(crate) fn push_punctuated<T, P: SpannedToken>(punctuated: &mut Punctuated<T, P>, value: T) {
+    if !punctuated.empty_or_trailing() {
+        punctuated.push_punct(spanned());
+    }
+    punctuated.push_value(value);
+}
+
+macro_rules! impl_spanned_token {
+    ($($token:ident),+ $(,)?) => {
+        $(
+            impl SpannedToken for token::$token {
+                fn from_span(span: Span) -> Self {
+                    token::$token(span)
+                }
+            }
+        )*
+    };
+}
+
+impl_spanned_token!(
+    Abstract, As, Async, Auto, Await, Become, Box, Break, Const, Continue, Crate, Default, Do, Dyn,
+    Else, Enum, Extern, Final, Fn, For, If, Impl, In, Let, Loop, Macro, Match, Mod, Move, Mut,
+    Override, Priv, Pub, Raw, Ref, Return, SelfType, SelfValue, Static, Struct, Super, Trait, Try,
+    Type, Typeof, Union, Unsafe, Unsized, Use, Virtual, Where, While, Yield, Underscore, And,
+    AndAnd, AndEq, At, Caret, CaretEq, Colon, Comma, Dollar, Dot, DotDot, DotDotDot, DotDotEq, Eq,
+    EqEq, FatArrow, Ge, Gt, LArrow, Le, Lt, Minus, MinusEq, Ne, Not, Or, OrEq, OrOr, PathSep,
+    Percent, PercentEq, Plus, PlusEq, Pound, Question, RArrow, Semi, Shl, ShlEq, Shr, ShrEq, Slash,
+    SlashEq, Star, StarEq, Tilde, Brace, Bracket, Paren, Group,
+);
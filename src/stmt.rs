@@ -1,13 +1,64 @@
-use crate::{attrs_builder, macros::AttrsPropsBuilder, IntoExpr, IntoPat};
-use syn::{Block, Expr, Item, Local, LocalInit, Macro, Stmt, StmtMacro};
+use crate::{attrs_builder, macros::AttrsPropsBuilder, span::spanned, IntoExpr, IntoPat};
+use syn::{Block, Expr, ExprMacro, Item, Local, LocalInit, Macro, MacroDelimiter, Stmt, StmtMacro};
 
 pub fn block<S: IntoStmt>(stmts: impl IntoIterator<Item = S>) -> Block {
+    let mut stmts: Vec<Stmt> = stmts.into_iter().map(IntoStmt::into_stmt).collect();
+    let tail = stmts.len().saturating_sub(1);
+    stmts[..tail].iter_mut().for_each(terminate_stmt);
+    stmts[tail..].iter_mut().for_each(tail_stmt);
+
     Block {
-        brace_token: Default::default(),
-        stmts: stmts.into_iter().map(IntoStmt::into_stmt).collect(),
+        brace_token: spanned(),
+        stmts,
+    }
+}
+
+fn terminate_stmt(stmt: &mut Stmt) {
+    match stmt {
+        Stmt::Expr(expr, semi_token @ None) if !is_block_like(expr) => {
+            *semi_token = Some(spanned());
+        }
+        Stmt::Macro(stmt_macro)
+            if !matches!(stmt_macro.mac.delimiter, MacroDelimiter::Brace(_)) =>
+        {
+            stmt_macro.semi_token.get_or_insert_with(spanned);
+        }
+        _ => {}
+    }
+}
+
+fn tail_stmt(stmt: &mut Stmt) {
+    if let Stmt::Macro(StmtMacro {
+        attrs,
+        mac,
+        semi_token: None,
+    }) = stmt
+    {
+        if !matches!(mac.delimiter, MacroDelimiter::Brace(_)) {
+            let expr = ExprMacro {
+                attrs: std::mem::take(attrs),
+                mac: mac.clone(),
+            };
+            *stmt = Stmt::Expr(Expr::Macro(expr), None);
+        }
     }
 }
 
+pub(crate) fn is_block_like(expr: &Expr) -> bool {
+    matches!(
+        expr,
+        Expr::Block(_)
+            | Expr::Const(_)
+            | Expr::ForLoop(_)
+            | Expr::If(_)
+            | Expr::Loop(_)
+            | Expr::Match(_)
+            | Expr::TryBlock(_)
+            | Expr::Unsafe(_)
+            | Expr::While(_)
+    )
+}
+
 pub trait BlockBuilder {
     fn new<S: IntoStmt>(stmts: impl IntoIterator<Item = S>) -> Self;
 }
@@ -46,19 +97,43 @@ impl IntoStmt for Expr {
     }
 }
 
+pub fn stmt_expr(expr: impl IntoExpr) -> Stmt {
+    Stmt::Expr(expr.into_expr(), None)
+}
+
+pub fn stmt_semi(expr: impl IntoExpr) -> Stmt {
+    Stmt::Expr(expr.into_expr(), Some(spanned()))
+}
+
+pub trait SemiBuilder {
+    fn semi(self) -> Stmt;
+}
+
+impl<E: IntoExpr> SemiBuilder for E {
+    fn semi(self) -> Stmt {
+        stmt_semi(self)
+    }
+}
+
 impl IntoStmt for StmtMacro {
     fn into_stmt(self) -> Stmt {
         Stmt::Macro(self)
     }
 }
 
+impl IntoStmt for Macro {
+    fn into_stmt(self) -> Stmt {
+        Stmt::Macro(stmt_macro(self))
+    }
+}
+
 pub fn local(pat: impl IntoPat) -> Local {
     Local {
         attrs: Default::default(),
-        let_token: Default::default(),
+        let_token: spanned(),
         pat: pat.into_pat(),
         init: None,
-        semi_token: Default::default(),
+        semi_token: spanned(),
     }
 }
 
@@ -84,7 +159,7 @@ impl LocalBuilder for Local {
 
 pub fn local_init(expr: impl IntoExpr) -> LocalInit {
     LocalInit {
-        eq_token: Default::default(),
+        eq_token: spanned(),
         expr: expr.into_expr().into(),
         diverge: None,
     }
@@ -102,7 +177,7 @@ impl LocalInitBuilder for LocalInit {
 
     fn diverge(self, expr: impl IntoExpr) -> Self {
         Self {
-            diverge: Some((Default::default(), expr.into_expr().into())),
+            diverge: Some((spanned(), expr.into_expr().into())),
             ..self
         }
     }
@@ -120,10 +195,18 @@ attrs_builder!(StmtMacro);
 
 pub trait StmtMacroBuilder: AttrsPropsBuilder {
     fn new(mac: impl Into<Macro>) -> Self;
+    fn semi_token(self, semi_token: bool) -> Self;
 }
 
 impl StmtMacroBuilder for StmtMacro {
     fn new(mac: impl Into<Macro>) -> Self {
         stmt_macro(mac)
     }
+
+    fn semi_token(self, semi_token: bool) -> Self {
+        Self {
+            semi_token: semi_token.then(spanned),
+            ..self
+        }
+    }
 }
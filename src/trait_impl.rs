@@ -0,0 +1,126 @@
+use crate::{
+    block,
+    generics::{generic_path, impl_generics},
+    impl_item_const, impl_item_fn, impl_item_type, item_impl, todo_macro, AttrsPropsBuilder,
+    IntoExpr, IntoIdent, IntoImplItem, IntoType, ItemGenericsPropsBuilder, ItemImplBuilder,
+    UnsafetyPropsBuilder,
+};
+use syn::{
+    Attribute, Block, Expr, Ident, ImplItem, ItemImpl, ItemTrait, Signature, TraitItem, Type,
+};
+
+pub struct TraitImpl<'a> {
+    trait_: ItemTrait,
+    self_ty: Type,
+    body: Box<dyn FnMut(&Signature) -> Block + 'a>,
+    types: Vec<(Ident, Type)>,
+    consts: Vec<(Ident, Expr)>,
+}
+
+pub fn trait_impl<'a>(trait_: impl Into<ItemTrait>, self_ty: impl IntoType) -> TraitImpl<'a> {
+    TraitImpl {
+        trait_: trait_.into(),
+        self_ty: self_ty.into_type(),
+        body: Box::new(|_| block([todo_macro().into_expr()])),
+        types: Vec::new(),
+        consts: Vec::new(),
+    }
+}
+
+impl<'a> TraitImpl<'a> {
+    pub fn body<B: Into<Block>>(self, mut body: impl FnMut(&Signature) -> B + 'a) -> Self {
+        Self {
+            body: Box::new(move |sig| body(sig).into()),
+            ..self
+        }
+    }
+
+    pub fn assoc_type(mut self, ident: impl IntoIdent, ty: impl IntoType) -> Self {
+        self.types.push((ident.into_ident(), ty.into_type()));
+        self
+    }
+
+    pub fn assoc_const(mut self, ident: impl IntoIdent, expr: impl IntoExpr) -> Self {
+        self.consts.push((ident.into_ident(), expr.into_expr()));
+        self
+    }
+
+    pub fn build(mut self) -> syn::Result<ItemImpl> {
+        let generics = impl_generics(&self.trait_.generics);
+        let trait_path = generic_path(&self.trait_.ident, &self.trait_.generics);
+        let items: Vec<ImplItem> = std::mem::take(&mut self.trait_.items)
+            .iter()
+            .filter_map(|item| self.impl_item(item).transpose())
+            .collect::<syn::Result<_>>()?;
+
+        Ok(item_impl(self.self_ty)
+            .unsafety(self.trait_.unsafety.is_some())
+            .generics(generics)
+            .trait_(false, trait_path)
+            .items(items))
+    }
+
+    fn impl_item(&mut self, item: &TraitItem) -> syn::Result<Option<ImplItem>> {
+        let item = match item {
+            TraitItem::Fn(item) if item.default.is_none() => {
+                impl_item_fn(item.sig.clone(), (self.body)(&item.sig))
+                    .attrs(cfg_attrs(&item.attrs))
+                    .into_impl_item()
+            }
+            TraitItem::Type(item) => {
+                let ty = assigned(&self.types, &item.ident)
+                    .or_else(|| item.default.as_ref().map(|(_, ty)| ty.clone()))
+                    .ok_or_else(|| {
+                        syn::Error::new(
+                            item.ident.span(),
+                            format!(
+                                "associated type `{}` of `{}` has no default, assign it with `assoc_type`",
+                                item.ident, self.trait_.ident
+                            ),
+                        )
+                    })?;
+                ImplItem::Type(syn::ImplItemType {
+                    generics: item.generics.clone(),
+                    ..impl_item_type(item.ident.clone(), ty).attrs(cfg_attrs(&item.attrs))
+                })
+            }
+            TraitItem::Const(item) => {
+                let expr = assigned(&self.consts, &item.ident)
+                    .or_else(|| item.default.as_ref().map(|(_, expr)| expr.clone()))
+                    .unwrap_or_else(|| todo_macro().into_expr());
+                ImplItem::Const(syn::ImplItemConst {
+                    generics: item.generics.clone(),
+                    ..impl_item_const(item.ident.clone(), item.ty.clone(), expr)
+                        .attrs(cfg_attrs(&item.attrs))
+                })
+            }
+            _ => return Ok(None),
+        };
+
+        Ok(Some(item))
+    }
+}
+
+impl TryFrom<TraitImpl<'_>> for ItemImpl {
+    type Error = syn::Error;
+
+    fn try_from(trait_impl: TraitImpl<'_>) -> syn::Result<Self> {
+        trait_impl.build()
+    }
+}
+
+fn assigned<T: Clone>(assignments: &[(Ident, T)], ident: &Ident) -> Option<T> {
+    assignments
+        .iter()
+        .rev()
+        .find(|(name, _)| name == ident)
+        .map(|(_, value)| value.clone())
+}
+
+fn cfg_attrs(attrs: &[Attribute]) -> Vec<Attribute> {
+    attrs
+        .iter()
+        .filter(|attr| attr.path().is_ident("cfg"))
+        .cloned()
+        .collect()
+}
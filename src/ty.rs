@@ -1,14 +1,15 @@
 use crate::{
     attrs_builder,
     macros::{AttrsPropsBuilder, MutabilityPropsBuilder, OutputPropsBuilder, QSelfPropsBuilder},
-    mutability_builder, output_builder, qself_builder, IntoExpr, IntoIdent, IntoPath,
-    IntoTypeParamBound,
+    mutability_builder, output_builder, qself_builder,
+    span::{parse_spanned, punctuated, spanned},
+    IntoExpr, IntoIdent, IntoPath, IntoTypeParamBound,
 };
 use proc_macro2::TokenStream;
 use syn::{
-    Abi, BareFnArg, BareVariadic, BoundLifetimes, Lifetime, LitStr, Macro, ReturnType, Type,
-    TypeArray, TypeBareFn, TypeGroup, TypeImplTrait, TypeInfer, TypeMacro, TypeNever, TypeParen,
-    TypePath, TypePtr, TypeReference, TypeSlice, TypeTraitObject, TypeTuple,
+    punctuated::Punctuated, Abi, BareFnArg, BareVariadic, BoundLifetimes, Lifetime, LitStr, Macro,
+    ReturnType, Type, TypeArray, TypeBareFn, TypeGroup, TypeImplTrait, TypeInfer, TypeMacro,
+    TypeNever, TypeParen, TypePath, TypePtr, TypeReference, TypeSlice, TypeTraitObject, TypeTuple,
 };
 
 pub trait IntoType {
@@ -21,6 +22,16 @@ impl IntoType for Type {
     }
 }
 
+impl IntoType for &str {
+    fn into_type(self) -> Type {
+        try_type(self).unwrap_or_else(|err| panic!("invalid type `{self}`: {err}"))
+    }
+}
+
+pub fn try_type(ty: &str) -> syn::Result<Type> {
+    parse_spanned(ty)
+}
+
 macro_rules! impl_into_type {
     ($($target:ident($type:ty),)*) => {
         $(
@@ -53,9 +64,9 @@ impl_into_type!(
 
 pub fn type_array(elem: impl IntoType, len: impl IntoExpr) -> TypeArray {
     TypeArray {
-        bracket_token: Default::default(),
+        bracket_token: spanned(),
         elem: elem.into_type().into(),
-        semi_token: Default::default(),
+        semi_token: spanned(),
         len: len.into_expr(),
     }
 }
@@ -75,9 +86,9 @@ pub fn type_bare_fn<I: Into<BareFnArg>>(inputs: impl IntoIterator<Item = I>) ->
         lifetimes: None,
         unsafety: None,
         abi: None,
-        fn_token: Default::default(),
-        paren_token: Default::default(),
-        inputs: FromIterator::from_iter(inputs.into_iter().map(Into::into)),
+        fn_token: spanned(),
+        paren_token: spanned(),
+        inputs: punctuated(inputs.into_iter().map(Into::into)),
         variadic: None,
         output: ReturnType::Default,
     }
@@ -107,7 +118,7 @@ impl TypeBareFnBuilder for TypeBareFn {
 
     fn unsafety(self, unsafety: bool) -> Self {
         Self {
-            unsafety: unsafety.then(Default::default),
+            unsafety: unsafety.then(spanned),
             ..self
         }
     }
@@ -119,7 +130,11 @@ impl TypeBareFnBuilder for TypeBareFn {
         }
     }
 
-    fn variadic(self, variadic: impl Into<BareVariadic>) -> Self {
+    fn variadic(mut self, variadic: impl Into<BareVariadic>) -> Self {
+        if !self.inputs.empty_or_trailing() {
+            self.inputs.push_punct(spanned());
+        }
+
         Self {
             variadic: Some(variadic.into()),
             ..self
@@ -129,7 +144,7 @@ impl TypeBareFnBuilder for TypeBareFn {
 
 pub fn type_group(elem: impl IntoType) -> TypeGroup {
     TypeGroup {
-        group_token: Default::default(),
+        group_token: spanned(),
         elem: elem.into_type().into(),
     }
 }
@@ -148,8 +163,8 @@ pub fn type_impl_trait<B: IntoTypeParamBound>(
     bounds: impl IntoIterator<Item = B>,
 ) -> TypeImplTrait {
     TypeImplTrait {
-        impl_token: Default::default(),
-        bounds: FromIterator::from_iter(
+        impl_token: spanned(),
+        bounds: punctuated(
             bounds
                 .into_iter()
                 .map(IntoTypeParamBound::into_type_param_bound),
@@ -169,7 +184,7 @@ impl TypeImplTraitBuilder for TypeImplTrait {
 
 pub fn type_infer() -> TypeInfer {
     TypeInfer {
-        underscore_token: Default::default(),
+        underscore_token: spanned(),
     }
 }
 
@@ -199,7 +214,7 @@ impl TypeMacroBuilder for TypeMacro {
 
 pub fn type_never() -> TypeNever {
     TypeNever {
-        bang_token: Default::default(),
+        bang_token: spanned(),
     }
 }
 
@@ -215,7 +230,7 @@ impl TypeNeverBuilder for TypeNever {
 
 pub fn type_paren(elem: impl IntoType) -> TypeParen {
     TypeParen {
-        paren_token: Default::default(),
+        paren_token: spanned(),
         elem: elem.into_type().into(),
     }
 }
@@ -251,8 +266,8 @@ impl TypePathBuilder for TypePath {
 
 pub fn type_ptr_const(elem: impl IntoType) -> TypePtr {
     TypePtr {
-        star_token: Default::default(),
-        const_token: Some(Default::default()),
+        star_token: spanned(),
+        const_token: Some(spanned()),
         mutability: None,
         elem: elem.into_type().into(),
     }
@@ -260,9 +275,9 @@ pub fn type_ptr_const(elem: impl IntoType) -> TypePtr {
 
 pub fn type_ptr_mut(elem: impl IntoType) -> TypePtr {
     TypePtr {
-        star_token: Default::default(),
+        star_token: spanned(),
         const_token: None,
-        mutability: Some(Default::default()),
+        mutability: Some(spanned()),
         elem: elem.into_type().into(),
     }
 }
@@ -283,7 +298,7 @@ impl TypePtrBuilder for TypePtr {
 
 pub fn type_reference(elem: impl IntoType) -> TypeReference {
     TypeReference {
-        and_token: Default::default(),
+        and_token: spanned(),
         lifetime: None,
         mutability: None,
         elem: elem.into_type().into(),
@@ -312,7 +327,7 @@ impl TypeReferenceBuilder for TypeReference {
 
 pub fn type_slice(elem: impl IntoType) -> TypeSlice {
     TypeSlice {
-        bracket_token: Default::default(),
+        bracket_token: spanned(),
         elem: elem.into_type().into(),
     }
 }
@@ -331,8 +346,8 @@ pub fn type_trait_object<B: IntoTypeParamBound>(
     bounds: impl IntoIterator<Item = B>,
 ) -> TypeTraitObject {
     TypeTraitObject {
-        dyn_token: Default::default(),
-        bounds: FromIterator::from_iter(
+        dyn_token: Some(spanned()),
+        bounds: punctuated(
             bounds
                 .into_iter()
                 .map(IntoTypeParamBound::into_type_param_bound),
@@ -351,9 +366,14 @@ impl TypeTraitObjectBuilder for TypeTraitObject {
 }
 
 pub fn type_tuple<E: IntoType>(elems: impl IntoIterator<Item = E>) -> TypeTuple {
+    let mut elems: Punctuated<_, _> = punctuated(elems.into_iter().map(IntoType::into_type));
+    if elems.len() == 1 {
+        elems.push_punct(spanned());
+    }
+
     TypeTuple {
-        paren_token: Default::default(),
-        elems: FromIterator::from_iter(elems.into_iter().map(IntoType::into_type)),
+        paren_token: spanned(),
+        elems,
     }
 }
 
@@ -369,7 +389,7 @@ impl TypeTupleBuilder for TypeTuple {
 
 pub fn abi(name: impl Into<LitStr>) -> Abi {
     Abi {
-        extern_token: Default::default(),
+        extern_token: spanned(),
         name: Some(name.into()),
     }
 }
@@ -406,7 +426,7 @@ impl BareFnArgBuilder for BareFnArg {
 
     fn name(self, name: impl IntoIdent) -> Self {
         Self {
-            name: Some((name.into_ident(), Default::default())),
+            name: Some((name.into_ident(), spanned())),
             ..self
         }
     }
@@ -416,7 +436,7 @@ pub fn bare_variadic() -> BareVariadic {
     BareVariadic {
         attrs: Default::default(),
         name: None,
-        dots: Default::default(),
+        dots: spanned(),
         comma: None,
     }
 }
@@ -435,7 +455,7 @@ impl BareVariadicBuilder for BareVariadic {
 
     fn name(self, name: impl IntoIdent) -> Self {
         Self {
-            name: Some((name.into_ident(), Default::default())),
+            name: Some((name.into_ident(), spanned())),
             ..self
         }
     }
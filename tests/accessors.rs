@@ -0,0 +1,121 @@
+mod common;
+
+use quote::quote;
+use syn::{parse_quote, ItemStruct};
+use syn_builder::*;
+
+fn tokens(accessors: Accessors) -> String {
+    common::tokens([accessors.build().unwrap().into_item()])
+}
+
+fn user() -> ItemStruct {
+    parse_quote! {
+        pub struct User {
+            /// Account id.
+            id: u64,
+            name: String,
+            tags: Vec<String>,
+            address: Address,
+        }
+    }
+}
+
+#[test]
+#[rustfmt::skip]
+fn getters_by_type_mut_and_set() {
+    assert_eq!(
+        tokens(accessors(user())),
+        quote! {
+            impl User {
+                /// Account id.
+                pub fn id(&self) -> u64 { self.id }
+                /// Account id.
+                pub fn id_mut(&mut self) -> &mut u64 { &mut self.id }
+                /// Account id.
+                pub fn set_id(&mut self, value: u64) { self.id = value; }
+                pub fn name(&self) -> &str { self.name.as_str() }
+                pub fn name_mut(&mut self) -> &mut String { &mut self.name }
+                pub fn set_name(&mut self, value: String) { self.name = value; }
+                pub fn tags(&self) -> &[String] { self.tags.as_slice() }
+                pub fn tags_mut(&mut self) -> &mut Vec<String> { &mut self.tags }
+                pub fn set_tags(&mut self, value: Vec<String>) { self.tags = value; }
+                pub fn address(&self) -> &Address { &self.address }
+                pub fn address_mut(&mut self) -> &mut Address { &mut self.address }
+                pub fn set_address(&mut self, value: Address) { self.address = value; }
+            }
+        }
+        .to_string()
+    );
+}
+
+#[test]
+fn field_attributes() {
+    let item: ItemStruct = parse_quote! {
+        struct Point {
+            #[accessors(get)]
+            x: f32,
+            #[accessors(skip)]
+            y: f32,
+            #[accessors(get, set, copy)]
+            origin: Origin,
+            #[accessors(by_ref, get)]
+            weight: u8,
+        }
+    };
+
+    let output = tokens(accessors(item.clone()).vis(vis_restricted("crate")));
+    assert!(output.contains("pub (crate) fn x (& self) -> f32 { self . x }"));
+    assert!(!output.contains("x_mut"));
+    assert!(!output.contains("set_x"));
+    assert!(!output.contains(" y"));
+    assert!(output.contains("fn origin (& self) -> Origin { self . origin }"));
+    assert!(output.contains("fn set_origin"));
+    assert!(!output.contains("origin_mut"));
+    assert!(output.contains("fn weight (& self) -> & u8 { & self . weight }"));
+
+    let stripped = strip_accessor_attrs(item);
+    assert!(stripped.fields.iter().all(|field| field.attrs.is_empty()));
+}
+
+#[test]
+fn configure_closure_and_generics() {
+    let item: ItemStruct = parse_quote! {
+        struct Wrapper<'a, T: Clone = u8> {
+            inner: &'a T,
+            r#type: Vec<T>,
+        }
+    };
+
+    let output = tokens(accessors(item).configure(|field, options| {
+        options.set = false;
+        options.copy = field.ident.as_ref().unwrap() == "inner";
+    }));
+    assert!(output.starts_with("impl < 'a , T : Clone > Wrapper < 'a , T >"));
+    assert!(output.contains("pub fn inner (& self) -> & 'a T { self . inner }"));
+    assert!(output.contains("pub fn r#type (& self) -> & [T]"));
+    assert!(output.contains("pub fn type_mut (& mut self) -> & mut Vec < T >"));
+    assert!(!output.contains("set_"));
+}
+
+#[test]
+fn invalid_input_is_a_spanned_error() {
+    let pair: ItemStruct = syn::parse_str("struct Pair(u8, u8);").unwrap();
+    let err = accessors(pair).build().unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "`Pair` needs named fields to get accessors"
+    );
+    assert_eq!(err.span().start().column, 7);
+
+    let item: ItemStruct =
+        syn::parse_str("struct S {\n    #[accessors(get, nope)]\n    x: u8,\n}").unwrap();
+    let err = accessors(item).build().unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "expected `skip`, `get`, `get_mut`, `set`, `copy` or `by_ref`"
+    );
+    assert_eq!(
+        (err.span().start().line, err.span().start().column),
+        (2, 21)
+    );
+}
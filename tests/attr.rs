@@ -0,0 +1,43 @@
+use quote::ToTokens;
+use syn::{Meta, MetaList};
+use syn_builder::*;
+
+fn tokens(meta: MetaList) -> String {
+    meta.to_token_stream().to_string()
+}
+
+#[test]
+fn meta_list_arg_and_args_append() {
+    assert_eq!(
+        tokens(
+            meta_list("serde", ["default"])
+                .arg("skip")
+                .args(["flatten"])
+        ),
+        "serde (default , skip , flatten)"
+    );
+    assert_eq!(
+        tokens(
+            meta_list("serde", Vec::<Meta>::new())
+                .args(["a", "b"])
+                .args(["c"])
+        ),
+        "serde (a , b , c)"
+    );
+    assert_eq!(
+        tokens(meta_list("cfg", ["unix"]).args(Vec::<Meta>::new())),
+        "cfg (unix)"
+    );
+}
+
+#[test]
+fn meta_list_roundtrips_as_attribute() {
+    let meta = meta_list("allow", ["dead_code"])
+        .arg(meta_name_value("note", "text"))
+        .args(["unused"]);
+    assert_roundtrip(
+        &item_struct("S", fields_named(Vec::<syn::Field>::new()))
+            .attrs([attribute(meta)])
+            .into_item(),
+    );
+}
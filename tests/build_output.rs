@@ -0,0 +1,60 @@
+#![cfg(feature = "build")]
+
+use std::{fs, path::PathBuf};
+use syn_builder::*;
+
+fn temp_out_dir(name: &str) -> PathBuf {
+    let dir = std::env::temp_dir().join(format!("syn_builder_{name}_{}", std::process::id()));
+    let _ = fs::remove_dir_all(&dir);
+    dir
+}
+
+fn generated() -> syn::File {
+    file([item_const("ANSWER", "u32", 42u32)])
+}
+
+#[test]
+fn writes_formatted_file_into_out_dir() {
+    let out_dir = temp_out_dir("writes");
+    let output = build_output("bindings")
+        .out_dir(&out_dir)
+        .header("@generated");
+
+    let path = output.write(generated()).unwrap();
+
+    assert_eq!(path, out_dir.join("bindings.rs"));
+    assert_eq!(
+        fs::read_to_string(&path).unwrap(),
+        "// @generated\n\nconst ANSWER: u32 = 42u32;\n"
+    );
+
+    fs::remove_dir_all(out_dir).unwrap();
+}
+
+#[test]
+fn leaves_unchanged_file_untouched() {
+    let out_dir = temp_out_dir("unchanged");
+    let output = build_output("bindings.rs").out_dir(&out_dir);
+
+    let path = output.write(generated()).unwrap();
+    let modified = fs::metadata(&path).unwrap().modified().unwrap();
+    std::thread::sleep(std::time::Duration::from_millis(20));
+    output.write(generated()).unwrap();
+
+    assert_eq!(fs::metadata(&path).unwrap().modified().unwrap(), modified);
+
+    fs::remove_dir_all(out_dir).unwrap();
+}
+
+#[test]
+fn emits_rerun_if_changed_for_inputs() {
+    let output = build_output("bindings").inputs(["schema.json", "build.rs"]);
+
+    assert_eq!(
+        output.rerun_if_changed(),
+        [
+            "cargo:rerun-if-changed=schema.json",
+            "cargo:rerun-if-changed=build.rs",
+        ]
+    );
+}
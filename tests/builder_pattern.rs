@@ -0,0 +1,152 @@
+mod common;
+
+use common::tokens;
+use quote::quote;
+use syn::{parse_quote, ItemStruct};
+use syn_builder::*;
+
+fn request() -> ItemStruct {
+    parse_quote! {
+        pub struct Request {
+            /// Target url.
+            url: String,
+            retries: u8,
+            timeout: Option<u64>,
+        }
+    }
+}
+
+#[test]
+#[rustfmt::skip]
+fn builder_struct_setters_and_build() {
+    assert_eq!(
+        tokens(builder_pattern(request()).default("retries", 3u8).build().unwrap()),
+        quote! {
+            pub struct RequestBuilder {
+                url: Option<String>,
+                retries: Option<u8>,
+                timeout: Option<u64>
+            }
+            impl Request {
+                pub fn builder() -> RequestBuilder {
+                    RequestBuilder { url: None, retries: None, timeout: None }
+                }
+            }
+            impl RequestBuilder {
+                /// Target url.
+                pub fn url(mut self, value: String) -> Self {
+                    self.url = Some(value);
+                    self
+                }
+                pub fn retries(mut self, value: u8) -> Self {
+                    self.retries = Some(value);
+                    self
+                }
+                pub fn timeout(mut self, value: u64) -> Self {
+                    self.timeout = Some(value);
+                    self
+                }
+                pub fn build(self) -> Result<Request, MissingField> {
+                    Ok(Request {
+                        url: self.url.ok_or(MissingField("url"))?,
+                        retries: self.retries.unwrap_or(3u8),
+                        timeout: self.timeout
+                    })
+                }
+            }
+        }
+        .to_string()
+    );
+}
+
+#[test]
+fn into_setters_and_lazy_defaults() {
+    let items = builder_pattern(request())
+        .into_setters(true)
+        .default("url", macro_call("format", [expr_lit("http://localhost")]))
+        .build()
+        .unwrap();
+
+    let output = tokens(items);
+    assert!(output.contains("pub fn url (mut self , value : impl Into < String >) -> Self"));
+    assert!(output.contains("self . url = Some (value . into ())"));
+    assert!(output.contains("pub fn timeout (mut self , value : impl Into < u64 >) -> Self"));
+    assert!(
+        output.contains("url : self . url . unwrap_or_else (| | format ! (\"http://localhost\"))")
+    );
+}
+
+#[test]
+fn optional_defaults_stay_optional() {
+    let output = tokens(
+        builder_pattern(request())
+            .default("timeout", 5u64)
+            .build()
+            .unwrap(),
+    );
+    assert!(output.contains("timeout : self . timeout . or (Some (5u64))"));
+
+    let output = tokens(
+        builder_pattern(request())
+            .default(
+                "timeout",
+                expr_method_call(expr_path("u64"), "pow", [expr_lit(2u32)]),
+            )
+            .build()
+            .unwrap(),
+    );
+    assert!(output.contains("timeout : self . timeout . or_else (| | Some (u64 . pow (2u32)))"));
+}
+
+#[test]
+fn generics_visibility_and_names() {
+    let item: ItemStruct = parse_quote! {
+        pub(crate) struct Pair<'a, T: Clone = u8> where T: Send {
+            left: &'a T,
+            right: T,
+        }
+    };
+
+    let output = tokens(
+        builder_pattern(item)
+            .name("PairMaker")
+            .error("crate::Missing")
+            .build()
+            .unwrap(),
+    );
+    assert!(output.starts_with(
+        "pub (crate) struct PairMaker < 'a , T : Clone = u8 > where T : Send { left : Option < & 'a T > ,"
+    ));
+    assert!(output.contains(
+        "impl < 'a , T : Clone > Pair < 'a , T > where T : Send { pub (crate) fn builder () -> PairMaker < 'a , T >"
+    ));
+    assert!(output
+        .contains("pub (crate) fn build (self) -> Result < Pair < 'a , T > , crate :: Missing >"));
+    assert!(output.contains("self . left . ok_or (crate :: Missing (\"left\")) ?"));
+}
+
+#[test]
+fn missing_field_type() {
+    let output = tokens(missing_field_error());
+
+    assert!(output.starts_with("# [derive (Debug , Clone , Copy , PartialEq , Eq)] pub struct MissingField (pub & 'static str) ;"));
+    assert!(output.contains("write ! (f , \"missing field `{}`\" , self . 0)"));
+    assert!(output.ends_with("impl :: std :: error :: Error for MissingField { }"));
+}
+
+#[test]
+fn emit_error_appends_missing_field_type() {
+    let output = tokens(builder_pattern(request()).emit_error(true).build().unwrap());
+
+    assert!(output.contains("Result < Request , MissingField >"));
+    assert!(output.ends_with(&tokens(missing_field_error())));
+}
+
+#[test]
+fn tuple_structs_are_rejected() {
+    let item: ItemStruct = syn::parse_str("struct Id(u32);").unwrap();
+    let err = builder_pattern(item).build().unwrap_err();
+
+    assert_eq!(err.to_string(), "`Id` needs named fields to get a builder");
+    assert_eq!(err.span().start().column, 7);
+}
@@ -0,0 +1,20 @@
+#![allow(dead_code)]
+
+use proc_macro2::TokenStream;
+use quote::ToTokens;
+use syn::{Item, ItemImpl};
+use syn_builder::*;
+
+pub fn tokens(items: impl IntoIterator<Item = Item>) -> String {
+    items
+        .into_iter()
+        .inspect(assert_roundtrip)
+        .map(|item| item.to_token_stream().to_string())
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+pub fn assert_impl(item: ItemImpl, expected: TokenStream) {
+    assert_eq!(item.to_token_stream().to_string(), expected.to_string());
+    assert_roundtrip(&item.into_item());
+}
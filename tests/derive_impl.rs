@@ -0,0 +1,256 @@
+mod common;
+
+use common::assert_impl;
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{parse_quote, DeriveInput, ItemEnum, ItemStruct};
+use syn_builder::*;
+
+fn assert_derive(derive: DeriveImpl, expected: TokenStream) {
+    assert_impl(derive.build().unwrap(), expected);
+}
+
+fn error(derive: DeriveImpl) -> (String, usize) {
+    let err = derive.build().unwrap_err();
+    (err.to_string(), err.span().start().column)
+}
+
+fn point() -> ItemStruct {
+    parse_quote! {
+        struct Point<T = u8> {
+            x: T,
+            y: T,
+            cache: Vec<u8>,
+        }
+    }
+}
+
+fn shape() -> ItemEnum {
+    parse_quote! {
+        enum Shape {
+            Empty,
+            Circle(f32),
+            Rect { w: f32, h: f32 },
+        }
+    }
+}
+
+#[test]
+#[rustfmt::skip]
+fn clone_skips_to_default() {
+    assert_derive(
+        derive_clone(point()).skip("cache"),
+        quote! {
+            impl<T> ::core::clone::Clone for Point<T> where T: ::core::clone::Clone {
+                fn clone(&self) -> Self {
+                    Self {
+                        x: ::core::clone::Clone::clone(&self.x),
+                        y: ::core::clone::Clone::clone(&self.y),
+                        cache: ::core::default::Default::default()
+                    }
+                }
+            }
+        },
+    );
+}
+
+#[test]
+fn debug_struct_and_tuple() {
+    assert_derive(
+        derive_debug(point()).skip("cache").with("y", "hex"),
+        quote! {
+            impl<T> ::core::fmt::Debug for Point<T> where T: ::core::fmt::Debug {
+                fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                    f.debug_struct("Point")
+                        .field("x", &self.x)
+                        .field("y", &hex(&self.y))
+                        .finish_non_exhaustive()
+                }
+            }
+        },
+    );
+
+    assert_derive(
+        derive_debug(shape()),
+        quote! {
+            impl ::core::fmt::Debug for Shape {
+                fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                    match self {
+                        Self::Empty => f.write_str("Empty"),
+                        Self::Circle(__self_0) => f.debug_tuple("Circle").field(__self_0).finish(),
+                        Self::Rect { w: __self_0, h: __self_1 } => f
+                            .debug_struct("Rect")
+                            .field("w", __self_0)
+                            .field("h", __self_1)
+                            .finish(),
+                    }
+                }
+            }
+        },
+    );
+}
+
+#[test]
+fn partial_eq_and_eq() {
+    assert_derive(
+        derive_partial_eq(point()).skip("cache"),
+        quote! {
+            impl<T> ::core::cmp::PartialEq for Point<T> where T: ::core::cmp::PartialEq {
+                fn eq(&self, other: &Self) -> bool {
+                    self.x == other.x && self.y == other.y
+                }
+            }
+        },
+    );
+
+    assert_derive(
+        derive_partial_eq(shape()).with("h", "approx_eq"),
+        quote! {
+            impl ::core::cmp::PartialEq for Shape {
+                fn eq(&self, other: &Self) -> bool {
+                    match (self, other) {
+                        (Self::Empty, Self::Empty) => true,
+                        (Self::Circle(__self_0), Self::Circle(__other_0)) => __self_0 == __other_0,
+                        (Self::Rect { w: __self_0, h: __self_1 }, Self::Rect { w: __other_0, h: __other_1 }) =>
+                            __self_0 == __other_0 && approx_eq(__self_1, __other_1),
+                        _ => false,
+                    }
+                }
+            }
+        },
+    );
+
+    assert_derive(
+        derive_eq(point()),
+        quote!(
+            impl<T> ::core::cmp::Eq for Point<T> where T: ::core::cmp::Eq {}
+        ),
+    );
+}
+
+#[test]
+fn hash_includes_discriminant() {
+    assert_derive(
+        derive_hash(shape()).skip("w"),
+        quote! {
+            impl ::core::hash::Hash for Shape {
+                fn hash<__H: ::core::hash::Hasher>(&self, state: &mut __H) {
+                    ::core::hash::Hash::hash(&::core::mem::discriminant(self), state);
+                    match self {
+                        Self::Empty => {}
+                        Self::Circle(__self_0) => {
+                            ::core::hash::Hash::hash(__self_0, state);
+                        }
+                        Self::Rect { w: _, h: __self_1 } => {
+                            ::core::hash::Hash::hash(__self_1, state);
+                        }
+                    }
+                }
+            }
+        },
+    );
+}
+
+#[test]
+fn partial_ord_compares_fields_in_order() {
+    assert_derive(
+        derive_partial_ord(point()).skip("cache"),
+        quote! {
+            impl<T> ::core::cmp::PartialOrd for Point<T> where T: ::core::cmp::PartialOrd {
+                fn partial_cmp(&self, other: &Self) -> ::core::option::Option<::core::cmp::Ordering> {
+                    match ::core::cmp::PartialOrd::partial_cmp(&self.x, &other.x) {
+                        ::core::option::Option::Some(::core::cmp::Ordering::Equal) =>
+                            ::core::cmp::PartialOrd::partial_cmp(&self.y, &other.y),
+                        cmp => cmp,
+                    }
+                }
+            }
+        },
+    );
+
+    assert_roundtrip(&derive_partial_ord(shape()).build().unwrap().into_item());
+}
+
+#[test]
+#[rustfmt::skip]
+fn default_variant() {
+    let mut marked = shape();
+    marked.variants[1].attrs.push(attribute("default"));
+
+    let expected = quote! {
+        impl ::core::default::Default for Shape {
+            fn default() -> Self {
+                Self::Circle(::core::default::Default::default())
+            }
+        }
+    };
+    assert_derive(derive_default(marked), expected.clone());
+    assert_derive(derive_default(shape()).default_variant("Circle"), expected);
+
+    assert_derive(
+        derive_default(point()).with("x", "origin"),
+        quote! {
+            impl<T> ::core::default::Default for Point<T> where T: ::core::default::Default {
+                fn default() -> Self {
+                    Self {
+                        x: origin(),
+                        y: ::core::default::Default::default(),
+                        cache: ::core::default::Default::default()
+                    }
+                }
+            }
+        },
+    );
+}
+
+#[test]
+fn custom_bounds_and_unused_params() {
+    let input: DeriveInput = parse_quote!(
+        struct Marker<T>(PhantomData<T>);
+    );
+
+    assert_derive(
+        derive_clone(input.clone()).bounds(Vec::<syn::WherePredicate>::new()),
+        quote! {
+            impl<T> ::core::clone::Clone for Marker<T> {
+                fn clone(&self) -> Self {
+                    Self(::core::clone::Clone::clone(&self.0))
+                }
+            }
+        },
+    );
+
+    assert_derive(
+        derive_hash(input).skip("0"),
+        quote! {
+            impl<T> ::core::hash::Hash for Marker<T> where T: ::core::hash::Hash {
+                fn hash<__H: ::core::hash::Hasher>(&self, _state: &mut __H) {}
+            }
+        },
+    );
+}
+
+#[test]
+fn unsupported_inputs_are_spanned_errors() {
+    let union: DeriveInput = syn::parse_str("pub union Bits { a: u8, b: i8 }").unwrap();
+    assert_eq!(
+        error(derive_clone(union)),
+        (
+            "`Bits` is a union, which std trait impls do not support".into(),
+            4
+        )
+    );
+
+    let shape: DeriveInput = syn::parse_str("enum Shape { A, B }").unwrap();
+    let variant = syn::parse_str::<syn::Ident>("    C").unwrap();
+    assert_eq!(
+        error(derive_default(shape).default_variant(variant)),
+        ("`Shape` has no variant `C`".into(), 4)
+    );
+
+    let empty: DeriveInput = syn::parse_str("enum Never {}").unwrap();
+    assert_eq!(
+        error(derive_default(empty)),
+        ("`Never` has no variant to use as the default".into(), 5)
+    );
+}
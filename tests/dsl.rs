@@ -0,0 +1,79 @@
+use quote::ToTokens;
+use syn::Expr;
+use syn_builder::*;
+
+fn a() -> E {
+    e(expr_path("a"))
+}
+
+fn b() -> E {
+    e(expr_path("b"))
+}
+
+fn tokens(expr: E) -> String {
+    assert_roundtrip(&expr.clone().into_expr());
+    expr.to_token_stream().to_string()
+}
+
+#[test]
+fn arithmetic_operators() {
+    assert_eq!(tokens(a() + b()), "a + b");
+    assert_eq!(tokens(a() - b()), "a - b");
+    assert_eq!(tokens((a() + b()) * b()), "(a + b) * b");
+    assert_eq!(tokens(a() / (b() - a())), "a / (b - a)");
+    assert_eq!(tokens(a() % b()), "a % b");
+    assert_eq!(tokens(a() - (b() - a())), "a - (b - a)");
+}
+
+#[test]
+fn bit_operators() {
+    assert_eq!(tokens(a() & b()), "a & b");
+    assert_eq!(tokens((a() | b()) ^ a()), "(a | b) ^ a");
+    assert_eq!(tokens(a() << 1u8), "a << 1u8");
+    assert_eq!(tokens((a() >> 2u8) + b()), "(a >> 2u8) + b");
+}
+
+#[test]
+fn unary_operators() {
+    assert_eq!(tokens(-a()), "- a");
+    assert_eq!(tokens(-(a() - b())), "- (a - b)");
+    assert_eq!(tokens(!a().and(b())), "! (a && b)");
+    assert_eq!(tokens((a() + b()).deref()), "* (a + b)");
+}
+
+#[test]
+fn comparison_and_logic_methods() {
+    assert_eq!(tokens(a().eq(b())), "a == b");
+    assert_eq!(tokens(a().ne(1u8)), "a != 1u8");
+    assert_eq!(tokens(a().lt(b()).or(a().ge(b()))), "a < b || a >= b");
+    assert_eq!(tokens(a().le(b()).and(a().gt(b()))), "a <= b && a > b");
+    assert_eq!(tokens(a().eq(b()).eq(a())), "(a == b) == a");
+}
+
+#[test]
+fn postfix_methods() {
+    assert_eq!(tokens(a().field("x").tuple_field(0)), "a . x . 0");
+    assert_eq!(tokens((a() + b()).field("x")), "(a + b) . x");
+    assert_eq!(tokens(a().call([b()])), "a (b)");
+    assert_eq!(
+        tokens((-a()).method("abs", Vec::<Expr>::new())),
+        "(- a) . abs ()"
+    );
+    assert_eq!(tokens(a().index(0usize)), "a [0usize]");
+    assert_eq!(tokens(a().await_().try_()), "a . await ?");
+}
+
+#[test]
+fn cast_and_reference_methods() {
+    assert_eq!(tokens(a().cast("u8")), "a as u8");
+    assert_eq!(tokens((a() + b()).cast("u8")), "(a + b) as u8");
+    assert_eq!(tokens(a().cast("u8").lt(b())), "(a as u8) < b");
+    assert_eq!(tokens(a().ref_()), "& a");
+    assert_eq!(tokens(a().ref_mut()), "& mut a");
+    assert_eq!(tokens((a() + b()).ref_()), "& (a + b)");
+}
+
+#[test]
+fn debug_prints_tokens() {
+    assert_eq!(format!("{:?}", a() + b()), "E(a + b)");
+}
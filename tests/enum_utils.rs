@@ -0,0 +1,145 @@
+mod common;
+
+use common::tokens;
+use quote::quote;
+use syn::{parse_quote, ItemEnum};
+use syn_builder::*;
+
+#[test]
+#[rustfmt::skip]
+fn unit_enum_gets_all_display_and_from_str() {
+    let item = item_enum("Color")
+        .vis(visibility_public_variant())
+        .variants([variant("Red"), variant("DarkBlue")]);
+
+    assert_eq!(
+        tokens(enum_utils(item).casing(Casing::Snake).build().unwrap()),
+        quote! {
+            impl Color {
+                pub const ALL: &[Self] = &[Self::Red, Self::DarkBlue];
+                pub fn is_red(&self) -> bool {
+                    match self {
+                        Self::Red => true,
+                        _ => false,
+                    }
+                }
+                pub fn is_dark_blue(&self) -> bool {
+                    match self {
+                        Self::DarkBlue => true,
+                        _ => false,
+                    }
+                }
+                pub fn variant_name(&self) -> &'static str {
+                    match self {
+                        Self::Red => "Red",
+                        Self::DarkBlue => "DarkBlue",
+                    }
+                }
+            }
+            impl ::core::fmt::Display for Color {
+                fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                    f.write_str(match self {
+                        Self::Red => "red",
+                        Self::DarkBlue => "dark_blue",
+                    })
+                }
+            }
+            impl ::core::str::FromStr for Color {
+                type Err = UnknownVariant;
+                fn from_str(s: &str) -> Result<Self, UnknownVariant> {
+                    match s {
+                        "red" => Ok(Self::Red),
+                        "dark_blue" => Ok(Self::DarkBlue),
+                        _ => Err(UnknownVariant(s.to_string())),
+                    }
+                }
+            }
+        }
+        .to_string()
+    );
+}
+
+#[test]
+fn data_variants_get_as_and_into() {
+    let item: ItemEnum = parse_quote! {
+        enum Shape<T> {
+            Circle(T),
+            Rect { w: T, h: T },
+            Pair(u8, String),
+            Empty,
+        }
+    };
+
+    let output = tokens(enum_utils(item).build().unwrap());
+    assert!(output.starts_with("impl < T > Shape < T >"));
+    assert!(output.contains(
+        "fn is_rect (& self) -> bool { match self { Self :: Rect { .. } => true , _ => false , } }"
+    ));
+    assert!(output.contains("fn as_circle (& self) -> Option < & T > { match self { Self :: Circle (field_0) => Some (field_0) , _ => None , } }"));
+    assert!(output.contains("fn into_rect (self) -> Option < (T , T) > { match self { Self :: Rect { w , h } => Some ((w , h)) , _ => None , } }"));
+    assert!(output.contains("fn as_pair (& self) -> Option < (& u8 , & String) >"));
+    assert!(!output.contains("as_empty"));
+    assert!(output.contains("Self :: Pair (..) => \"Pair\""));
+    assert!(!output.contains("ALL"));
+    assert!(!output.contains("Display"));
+    assert!(!output.contains("FromStr"));
+}
+
+#[test]
+fn repr_enum_gets_try_from() {
+    let item: ItemEnum = parse_quote! {
+        #[repr(u8)]
+        enum Level { Low = 1, Mid, High = 0x10 }
+    };
+
+    let output = tokens(
+        enum_utils(item)
+            .casing(Casing::ScreamingSnake)
+            .error("ParseLevelError")
+            .build()
+            .unwrap(),
+    );
+    assert!(output.contains(
+        "impl :: core :: convert :: TryFrom < u8 > for Level { type Error = u8 ; \
+         fn try_from (value : u8) -> Result < Self , u8 > { match value { \
+         1 => Ok (Self :: Low) , \
+         _ if value == Self :: Mid as u8 => Ok (Self :: Mid) , \
+         0x10 => Ok (Self :: High) , \
+         _ => Err (value) , } } }"
+    ));
+    assert!(output.contains("\"MID\" => Ok (Self :: Mid)"));
+    assert!(output.contains("Err (ParseLevelError (s . to_string ()))"));
+}
+
+#[test]
+fn single_and_empty_enums() {
+    let single: ItemEnum = parse_quote! { enum Single { Only(u8) } };
+    let output = tokens(enum_utils(single).build().unwrap());
+    assert!(output.contains("match self { Self :: Only (field_0) => Some (field_0) , }"));
+    assert!(output.contains("match self { Self :: Only (..) => true , }"));
+
+    let empty: ItemEnum = parse_quote! { enum Never {} };
+    let output = tokens(enum_utils(empty).build().unwrap());
+    assert!(output.contains("fn variant_name (& self) -> & 'static str { match * self { } }"));
+    assert!(output.contains("fn fmt (& self , _f : & mut :: core :: fmt :: Formatter < '_ >)"));
+    assert!(output.contains("{ Err (UnknownVariant (s . to_string ())) }"));
+}
+
+#[test]
+fn colliding_method_names_are_rejected() {
+    let item: ItemEnum = syn::parse_str("enum Key { Up, UP }").unwrap();
+    let err = enum_utils(item).build().unwrap_err();
+
+    assert_eq!(err.to_string(), "variants `Up` and `UP` both get `is_up`");
+    assert_eq!(err.span().start().column, 15);
+}
+
+#[test]
+fn unknown_variant_error_items() {
+    let output = tokens(unknown_variant_error());
+    assert!(output.starts_with(
+        "# [derive (Debug , Clone , PartialEq , Eq)] pub struct UnknownVariant (pub String) ;"
+    ));
+    assert!(output.contains("write ! (f , \"unknown variant `{}`\" , self . 0)"));
+    assert!(output.ends_with("impl :: std :: error :: Error for UnknownVariant { }"));
+}
@@ -0,0 +1,354 @@
+use proc_macro2::Span;
+use quote::{quote, ToTokens};
+use syn::{Expr, Lifetime};
+use syn_builder::*;
+
+fn roundtrip(expr: impl IntoExpr) {
+    assert_roundtrip(&expr.into_expr());
+}
+
+fn lifetime(name: &str) -> Lifetime {
+    Lifetime::new(name, Span::call_site())
+}
+
+fn x() -> Expr {
+    expr_path("x").into_expr()
+}
+
+#[test]
+fn array() {
+    roundtrip(expr_array([x(), x()]));
+    roundtrip(expr_array::<Expr>([]));
+}
+
+#[test]
+fn assign() {
+    roundtrip(expr_assign("a", 1u8));
+}
+
+#[test]
+fn async_block() {
+    roundtrip(expr_async(block([x()])));
+    roundtrip(expr_async(block([x()])).capture(true));
+}
+
+#[test]
+fn await_expr() {
+    roundtrip(expr_await(x()));
+}
+
+#[test]
+fn binary() {
+    roundtrip(expr_binary(x(), bin_op_add_variant(), 1u8));
+    roundtrip(expr_binary(x(), bin_op_shl_assign_variant(), 2u8));
+    roundtrip(parenthesize(expr_binary(
+        expr_binary(x(), bin_op_add_variant(), 1u8),
+        bin_op_mul_variant(),
+        x(),
+    )));
+}
+
+#[test]
+fn block_expr() {
+    roundtrip(expr_block(block([x()])));
+    roundtrip(expr_block(block([x()])).label(label(lifetime("'outer"))));
+}
+
+#[test]
+fn break_expr() {
+    roundtrip(expr_break());
+    roundtrip(expr_break().label(lifetime("'outer")).expr(x()));
+}
+
+#[test]
+fn call() {
+    roundtrip(expr_call(expr_path("f"), [x(), x()]));
+}
+
+#[test]
+fn cast() {
+    roundtrip(expr_cast(x(), "u8"));
+}
+
+#[test]
+fn closure() {
+    roundtrip(expr_closure([pat_ident("a")], x()));
+    roundtrip(
+        expr_closure([pat_type(pat_ident("a"), "u8")], expr_block(block([x()])))
+            .output("u8")
+            .capture(true),
+    );
+    roundtrip(
+        expr_closure::<syn::Pat>([], x())
+            .asyncness(true)
+            .capture(true),
+    );
+    roundtrip(
+        expr_closure([pat_ident("a")], x())
+            .lifetimes(bound_lifetimes([lifetime_param(lifetime("'a"))])),
+    );
+}
+
+#[test]
+fn const_block() {
+    roundtrip(expr_const(block([x()])));
+}
+
+#[test]
+fn continue_expr() {
+    roundtrip(expr_continue());
+    roundtrip(expr_continue().label(lifetime("'outer")));
+}
+
+#[test]
+fn field() {
+    roundtrip(expr_field(x(), syn::Member::Named("y".into_ident())));
+    roundtrip(expr_field(x(), index(0usize)));
+}
+
+#[test]
+fn for_loop() {
+    roundtrip(expr_for_loop(pat_ident("i"), x(), block([x()])));
+    roundtrip(expr_for_loop(pat_ident("i"), x(), block([x()])).label(label(lifetime("'outer"))));
+}
+
+#[test]
+fn if_expr() {
+    roundtrip(expr_if(x(), block([x()])));
+    roundtrip(expr_if(x(), block([x()])).else_branch(expr_block(block([x()]))));
+    roundtrip(expr_if(x(), block([x()])).else_branch(expr_if(x(), block([x()]))));
+    roundtrip(expr_if(expr_let(pat_ident("y"), x()), block([x()])));
+}
+
+#[test]
+fn index_expr() {
+    roundtrip(expr_index(x(), 0usize));
+}
+
+#[test]
+fn infer() {
+    roundtrip(expr_call(expr_path("f"), [expr_infer()]));
+}
+
+#[test]
+fn lit() {
+    roundtrip(expr_lit("text"));
+    roundtrip(expr_lit('c'));
+    roundtrip(expr_lit(true));
+    roundtrip(expr_lit(b"bytes".as_slice()));
+    roundtrip(expr_lit(7u64));
+    roundtrip(expr_lit(1.5f64));
+    roundtrip(-3i32);
+    roundtrip(-0.5f64);
+}
+
+#[test]
+fn loop_expr() {
+    roundtrip(expr_loop(block([x()])));
+    roundtrip(expr_loop(block([x()])).label(label(lifetime("'outer"))));
+}
+
+#[test]
+fn macro_expr() {
+    roundtrip(expr_macro(r#macro("vec", quote!(1, 2))));
+}
+
+#[test]
+fn match_expr() {
+    roundtrip(expr_match(x()));
+    roundtrip(
+        expr_match(x())
+            .arm(pat_lit(1u8), x())
+            .arm_guard(pat_ident("y"), expr_path("y"), x())
+            .wildcard(expr_block(block([x()]))),
+    );
+    roundtrip(expr_match(x()).arms([(pat_lit(1u8).into_pat(), x()), (pat_wild().into_pat(), x())]));
+}
+
+#[test]
+fn block_arms_have_no_comma() {
+    let match_ = expr_match(x())
+        .arm(pat_lit(1u8), expr_block(block([x()])))
+        .arm(pat_lit(2u8), expr_if(x(), block([x()])))
+        .wildcard(x());
+
+    assert!(match_.arms[0].comma.is_none());
+    assert!(match_.arms[1].comma.is_none());
+    assert!(match_.arms[2].comma.is_some());
+    roundtrip(match_);
+}
+
+#[test]
+fn if_chain_expr() {
+    let chain = if_chain()
+        .when(x(), block([x()]))
+        .when_let(
+            pat_tuple_struct("Some", [pat_ident("y")]),
+            expr_path("opt"),
+            block([expr_path("y").into_expr()]),
+        )
+        .otherwise(block([x()]));
+
+    assert_eq!(
+        chain.clone().into_expr().to_token_stream().to_string(),
+        "if x { x } else if let Some (y) = opt { y } else { x }"
+    );
+    roundtrip(chain);
+    roundtrip(if_chain().when(x(), block([x()])));
+    roundtrip(if_chain().otherwise(block([x()])));
+}
+
+#[test]
+fn if_chain_parenthesizes_conditions() {
+    let chain = if_chain().when_let(
+        pat_ident("y"),
+        e(expr_path("a")).or(expr_path("b")),
+        block([x()]),
+    );
+
+    assert_eq!(
+        chain.clone().into_expr().to_token_stream().to_string(),
+        "if let y = (a || b) { x }"
+    );
+    roundtrip(chain);
+    roundtrip(if_chain().when(
+        expr_struct("S", Vec::<syn::FieldValue>::new()),
+        block([x()]),
+    ));
+}
+
+#[test]
+fn method_call() {
+    roundtrip(expr_method_call(x(), "len", Vec::<Expr>::new()));
+    roundtrip(
+        expr_method_call(x(), "collect", Vec::<Expr>::new())
+            .turbofish(angle_bracketed_generic_arguments(["Vec<u8>".into_type()])),
+    );
+}
+
+#[test]
+fn paren() {
+    roundtrip(expr_paren(x()));
+}
+
+#[test]
+fn path_expr() {
+    roundtrip(expr_path("std::mem::swap"));
+    roundtrip(expr_path("Default::default").qself(q_self("T", 1usize).as_token(true)));
+}
+
+#[test]
+fn range() {
+    roundtrip(expr_range(range_limits_half_open_variant()));
+    roundtrip(
+        expr_range(range_limits_half_open_variant())
+            .start(0u8)
+            .end(x()),
+    );
+    roundtrip(expr_range(range_limits_closed_variant()).end(x()));
+}
+
+#[test]
+fn reference() {
+    roundtrip(expr_reference(x()));
+    roundtrip(expr_reference(x()).mutability(true));
+}
+
+#[test]
+fn repeat() {
+    roundtrip(expr_repeat(0u8, 4usize));
+}
+
+#[test]
+fn return_expr() {
+    roundtrip(expr_return());
+    roundtrip(expr_return().expr(x()));
+}
+
+#[test]
+fn struct_expr() {
+    roundtrip(expr_struct(
+        "Point",
+        [
+            field_value(syn::Member::Named("x".into_ident()), x()),
+            field_value(syn::Member::Named("y".into_ident()), 0u8),
+        ],
+    ));
+    roundtrip(
+        expr_struct(
+            "Point",
+            [field_value(syn::Member::Named("x".into_ident()), x())],
+        )
+        .rest(expr_call(expr_path("Default::default"), Vec::<Expr>::new())),
+    );
+}
+
+#[test]
+fn try_expr_() {
+    roundtrip(expr_try(x()));
+}
+
+#[test]
+fn try_block() {
+    roundtrip(expr_try_block(block([x()])));
+}
+
+#[test]
+fn tuple() {
+    roundtrip(expr_tuple([x(), x()]));
+    roundtrip(expr_tuple::<Expr>([]));
+}
+
+#[test]
+fn unary() {
+    roundtrip(expr_unary(un_op_not(), x()));
+    roundtrip(expr_unary(un_op_deref(), x()));
+}
+
+#[test]
+fn unsafe_block() {
+    roundtrip(expr_unsafe(block([x()])));
+}
+
+#[test]
+fn while_expr() {
+    roundtrip(expr_while(x(), block([x()])));
+    roundtrip(expr_while(expr_let(pat_ident("y"), x()), block([x()])));
+}
+
+#[test]
+fn yield_expr() {
+    roundtrip(expr_yield());
+    roundtrip(expr_yield().expr(x()));
+}
+
+#[test]
+fn blocks_terminate_statements() {
+    roundtrip(expr_block(block([
+        expr_call(expr_path("f"), Vec::<Expr>::new()).into_expr(),
+        expr_if(x(), block([x()])).into_expr(),
+        x(),
+    ])));
+}
+
+#[test]
+fn dsl() {
+    let a = e(expr_path("a"));
+    let b = e(expr_path("b"));
+
+    roundtrip((a.clone() + b.clone()) * b.clone());
+    roundtrip(a.clone().cast("u8").lt(b.clone()));
+    roundtrip((-(a.clone() - b.clone())).method("abs", Vec::<Expr>::new()));
+    roundtrip(!a.field("ready").and(b.index(0usize).eq(1u8)));
+}
+
+#[test]
+fn unparenthesized_tree_is_reported() {
+    let expr = expr_binary(
+        expr_binary(x(), bin_op_add_variant(), 1u8),
+        bin_op_mul_variant(),
+        x(),
+    );
+
+    assert!(try_roundtrip(&expr.into_expr()).is_err());
+}
@@ -0,0 +1,73 @@
+use proc_macro2::Span;
+use syn::{FnArg, GenericParam, Lifetime, WherePredicate};
+use syn_builder::*;
+
+fn lifetime(name: &str) -> Lifetime {
+    Lifetime::new(name, Span::call_site())
+}
+
+fn roundtrip_fn(generics: syn::Generics) {
+    assert_roundtrip(&syn::Item::Fn(item_fn(
+        signature("f", Vec::<FnArg>::new()).generics(generics),
+        block(Vec::<syn::Stmt>::new()),
+    )));
+}
+
+#[test]
+fn params() {
+    let params: [GenericParam; 6] = [
+        lifetime_param(lifetime("'a")).into_generic_param(),
+        lifetime_param(lifetime("'b"))
+            .bounds([lifetime("'a")])
+            .into_generic_param(),
+        type_param("T").into_generic_param(),
+        type_param("U")
+            .bounds([trait_bound("Clone"), trait_bound("Sized").modifier(true)])
+            .into_generic_param(),
+        type_param("V").default("u8").into_generic_param(),
+        const_param("N", "usize")
+            .default(4usize)
+            .into_generic_param(),
+    ];
+
+    for param in params.clone() {
+        assert_roundtrip(&param);
+    }
+
+    assert_roundtrip(&generics(params));
+    assert_roundtrip(&generics(Vec::<GenericParam>::new()));
+}
+
+#[test]
+fn where_clauses() {
+    let predicates: [WherePredicate; 3] = [
+        predicate_type("T", [trait_bound("Clone")]).into_where_predicate(),
+        predicate_lifetime(lifetime("'a"), [lifetime("'b")]).into_where_predicate(),
+        predicate_type("F", [trait_bound("Fn(&'x u8)")])
+            .lifetimes(bound_lifetimes([lifetime_param(lifetime("'x"))]))
+            .into_where_predicate(),
+    ];
+
+    assert_roundtrip(&where_clause(predicates.clone()));
+    roundtrip_fn(
+        generics([
+            lifetime_param(lifetime("'a")).into_generic_param(),
+            lifetime_param(lifetime("'b")).into_generic_param(),
+            type_param("T").into_generic_param(),
+            type_param("F").into_generic_param(),
+        ])
+        .where_clause(where_clause(predicates)),
+    );
+}
+
+#[test]
+fn trait_bounds() {
+    let bound =
+        trait_bound("Fn(&'a u8)").lifetimes(bound_lifetimes([lifetime_param(lifetime("'a"))]));
+
+    assert_roundtrip(&syn::TypeParamBound::Trait(bound));
+    assert_roundtrip(&syn::TypeParamBound::Trait(
+        trait_bound("Sized").modifier(true),
+    ));
+    assert_roundtrip(&lifetime("'static").into_type_param_bound());
+}
@@ -0,0 +1,61 @@
+use syn_builder::*;
+
+fn name(ident: proc_macro2::Ident) -> String {
+    ident.to_string()
+}
+
+#[test]
+fn safe_replaces_invalid_characters() {
+    assert_eq!(name(ident_safe("foo-bar baz")), "foo_bar_baz");
+    assert_eq!(name(ident_safe("a²")), "a_");
+    assert_eq!(name(ident_safe("²")), "__");
+    assert_eq!(name(ident_safe("über")), "über");
+    assert_eq!(name(ident_safe("1st")), "_1st");
+    assert_eq!(name(ident_safe("")), "__");
+    assert_eq!(name(ident_safe("_")), "__");
+}
+
+#[test]
+fn safe_escapes_keywords() {
+    assert_eq!(name(ident_safe("type")), "r#type");
+    assert_eq!(name(ident_safe("match")), "r#match");
+    assert_eq!(name(ident_safe("self")), "self_");
+    assert_eq!(name(ident_safe("Self")), "Self_");
+}
+
+#[test]
+fn raw() {
+    assert_eq!(name(ident_raw("fn")), "r#fn");
+    assert_eq!(name(ident_raw("value")), "r#value");
+}
+
+#[test]
+fn checked() {
+    assert_eq!(name(try_ident("value").unwrap()), "value");
+    assert_eq!(name(try_ident("r#loop").unwrap()), "r#loop");
+    assert_eq!(name(try_ident_escaped("loop").unwrap()), "r#loop");
+    for invalid in ["", "_", "1a", "a-b", "a²", "loop", "self"] {
+        assert!(try_ident(invalid).is_err(), "{invalid:?}");
+    }
+}
+
+#[test]
+fn case_conversion() {
+    assert_eq!(
+        name(ident_snake_case("HTTPServerError")),
+        "http_server_error"
+    );
+    assert_eq!(name(ident_snake_case("parseJSON2Html")), "parse_json2_html");
+    assert_eq!(name(ident_snake_case("already_snake")), "already_snake");
+    assert_eq!(name(ident_snake_case("Type")), "r#type");
+    assert_eq!(
+        name(ident_upper_camel_case("http_server-error")),
+        "HttpServerError"
+    );
+    assert_eq!(
+        name(ident_upper_camel_case("XMLHttpRequest")),
+        "XmlHttpRequest"
+    );
+    assert_eq!(name(ident_screaming_snake_case("maxValue")), "MAX_VALUE");
+    assert_eq!(name(ident_screaming_snake_case("v2Api")), "V2_API");
+}
@@ -0,0 +1,283 @@
+use proc_macro2::Span;
+use quote::quote;
+use syn::{FnArg, ImplItem, Item, Lifetime, TraitItem};
+use syn_builder::*;
+
+fn roundtrip(item: impl IntoItem) {
+    assert_roundtrip(&item.into_item());
+}
+
+fn lifetime(name: &str) -> Lifetime {
+    Lifetime::new(name, Span::call_site())
+}
+
+fn x(name: &str) -> syn::Expr {
+    expr_path(name).into_expr()
+}
+
+fn sig(name: &str) -> syn::Signature {
+    signature(name, Vec::<FnArg>::new())
+}
+
+#[test]
+fn const_item() {
+    roundtrip(item_const("MAX", "u32", 10u32));
+    roundtrip(item_const("MAX", "u32", 10u32).vis(visibility_public_variant()));
+}
+
+#[test]
+fn enum_item() {
+    roundtrip(item_enum("Empty"));
+    roundtrip(
+        item_enum("Shape")
+            .variants([
+                variant("Unit"),
+                variant("Tuple").fields(fields_unamed([field("u8"), field("u16")])),
+                variant("Named").fields(fields_named([field("u8").ident("x")])),
+            ])
+            .derive(["Clone", "Debug"]),
+    );
+    roundtrip(
+        item_enum("Repr")
+            .variant(variant("A").discriminant(1u8))
+            .variant(variant("B").discriminant(2u8))
+            .generics(generics([type_param("T")])),
+    );
+}
+
+#[test]
+fn extern_crate() {
+    roundtrip(item_extern_crate("alloc"));
+    roundtrip(item_extern_crate("std").rename("core_std"));
+}
+
+#[test]
+fn fn_item() {
+    roundtrip(item_fn(sig("f"), block(Vec::<syn::Stmt>::new())));
+    roundtrip(item_fn(
+        signature(
+            "add",
+            [
+                pat_type(pat_ident("a"), "u8"),
+                pat_type(pat_ident("b"), "u8"),
+            ],
+        )
+        .output("u8"),
+        block([expr_binary(expr_path("a"), bin_op_add_variant(), expr_path("b")).into_expr()]),
+    ));
+    roundtrip(
+        item_fn(
+            sig("g")
+                .constness(true)
+                .unsafety(true)
+                .generics(generics([type_param("T").bounds([trait_bound("Clone")])])),
+            block([x("x")]),
+        )
+        .vis(visibility_public_variant())
+        .doc("Documented."),
+    );
+    roundtrip(item_fn(sig("h").asyncness(true), block([x("x")])));
+}
+
+#[test]
+fn foreign_mod() {
+    roundtrip(
+        item_foreign_mod(abi(lit_str("C")))
+            .item(foreign_item_fn(signature(
+                "puts",
+                [pat_type(pat_ident("s"), "*const u8")],
+            )))
+            .item(foreign_item_static("errno", "i32"))
+            .item(foreign_item_static("state", "u8").mutability(true))
+            .item(foreign_item_type("Opaque")),
+    );
+}
+
+#[test]
+fn impl_item() {
+    roundtrip(item_impl("Foo"));
+    roundtrip(
+        item_impl("Foo")
+            .trait_(false, "Iterator")
+            .item(impl_item_type("Item", "u8"))
+            .item(impl_item_fn(
+                signature("next", [receiver().reference(true).mutability(true)])
+                    .output("Option<u8>"),
+                block([x("None")]),
+            )),
+    );
+    roundtrip(item_impl("Foo").trait_(true, "Send").unsafety(true));
+    roundtrip(
+        item_impl("Foo<T>")
+            .generics(generics([type_param("T")]))
+            .item(impl_item_const("N", "usize", 1usize))
+            .item(impl_item_macro(r#macro("m", quote!()))),
+    );
+}
+
+#[test]
+fn macro_item() {
+    roundtrip(item_macro("m", r#macro("macro_rules", quote!(() => {}))));
+}
+
+#[test]
+fn mod_item() {
+    roundtrip(item_mod("empty"));
+    roundtrip(item_mod("inner").content([item_const("A", "u8", 1u8)]));
+}
+
+#[test]
+fn static_item() {
+    roundtrip(item_static("COUNT", "u32", 0u32));
+    roundtrip(item_static("COUNT", "u32", 0u32).mutability(true));
+}
+
+#[test]
+fn struct_item() {
+    roundtrip(item_struct("Unit", syn::Fields::Unit));
+    roundtrip(item_struct("Tuple", fields_unamed([field("u8")])));
+    roundtrip(
+        item_struct(
+            "Named",
+            fields_named([field("u8").ident("x"), field("T").ident("y")]),
+        )
+        .generics(generics([type_param("T")]))
+        .derive(["Clone"]),
+    );
+}
+
+#[test]
+fn trait_item() {
+    roundtrip(item_trait("Empty"));
+    roundtrip(
+        item_trait("Shape")
+            .supertraits([trait_bound("Clone")])
+            .item(trait_item_const("SIDES", "u8"))
+            .item(trait_item_const("EDGES", "u8").default(0u8))
+            .item(trait_item_type("Output"))
+            .item(trait_item_type("Bounded").bounds([trait_bound("Copy")]))
+            .item(trait_item_fn(
+                signature("area", [receiver().reference(true)]).output("f64"),
+            ))
+            .item(trait_item_fn(sig("name")).default(block([expr_lit("shape").into_expr()])))
+            .item(trait_item_macro(r#macro("m", quote!()))),
+    );
+    roundtrip(item_trait("Marker").unsafety(true));
+}
+
+#[test]
+fn trait_alias() {
+    roundtrip(item_trait_alias(
+        "Both",
+        [trait_bound("Clone"), trait_bound("Send")],
+    ));
+}
+
+#[test]
+fn type_alias() {
+    roundtrip(item_type("Bytes", "Vec<u8>"));
+    roundtrip(item_type("List", "Vec<T>").generics(generics([type_param("T")])));
+}
+
+#[test]
+fn union_item() {
+    roundtrip(item_union(
+        "Bits",
+        fields_named([field("u32").ident("int"), field("f32").ident("float")]),
+    ));
+}
+
+#[test]
+fn use_item() {
+    roundtrip(item_use(use_path("std", use_path("mem", use_name("swap")))));
+    roundtrip(item_use(use_path("std", use_rename("fmt", "format"))));
+    roundtrip(item_use(use_path("std", use_glob())));
+    roundtrip(item_use(use_path(
+        "std",
+        use_group([
+            use_name("fs").into_use_tree(),
+            use_name("io").into_use_tree(),
+        ]),
+    )));
+    roundtrip(item_use(use_name("core")).leading(true));
+}
+
+#[test]
+fn receivers() {
+    let receivers = [
+        receiver(),
+        receiver().mutability(true),
+        receiver().reference(true),
+        receiver().reference(true).mutability(true),
+        receiver().lifetime(lifetime("'a")),
+        receiver().ty("Box<Self>"),
+    ];
+
+    for receiver in receivers {
+        assert_roundtrip(&TraitItem::Fn(trait_item_fn(signature("f", [receiver]))));
+    }
+}
+
+#[test]
+fn receiver_helpers() {
+    let method = |inputs: Vec<FnArg>| {
+        assert_roundtrip(&TraitItem::Fn(trait_item_fn(signature("f", inputs))));
+    };
+
+    method(vec![self_value().into_fn_arg()]);
+    method(vec![
+        self_ref().into_fn_arg(),
+        arg("x", "u32").into_fn_arg(),
+    ]);
+    method(vec![
+        self_mut().into_fn_arg(),
+        ("x", "u32").into_fn_arg(),
+        ("y", type_reference("str")).into_fn_arg(),
+    ]);
+
+    assert_eq!(*self_mut().ty, "&mut Self".into_type());
+    assert_eq!(*self_ref().mutability(false).ty, "&Self".into_type());
+    assert_eq!(*self_mut().reference(false).ty, "Self".into_type());
+}
+
+#[test]
+fn impl_items() {
+    let items: [ImplItem; 4] = [
+        impl_item_const("A", "u8", 1u8).into_impl_item(),
+        impl_item_fn(sig("f"), block([x("x")]))
+            .vis(visibility_public_variant())
+            .into_impl_item(),
+        impl_item_type("T", "u8").defaultness(true).into_impl_item(),
+        impl_item_macro(r#macro("m", quote!())).into_impl_item(),
+    ];
+
+    for item in items {
+        assert_roundtrip(&item);
+    }
+}
+
+#[test]
+fn variadic_fn() {
+    roundtrip(item_foreign_mod(abi(lit_str("C"))).item(foreign_item_fn(
+        signature("printf", [pat_type(pat_ident("fmt"), "*const u8")]).variadic(variadic()),
+    )));
+}
+
+#[test]
+fn items_in_file() {
+    assert_roundtrip(&file([
+        Item::from(item_use(use_path("std", use_name("fmt")))),
+        Item::from(item_struct("Unit", syn::Fields::Unit)),
+    ]));
+}
+
+#[test]
+fn file_docs_are_inner() {
+    let file = file([item_struct("Unit", syn::Fields::Unit)]).inner_doc("Generated.");
+
+    assert_roundtrip(&file);
+    assert_eq!(
+        quote!(#file).to_string(),
+        "# ! [doc = \" Generated.\"] struct Unit ;"
+    );
+}
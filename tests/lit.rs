@@ -0,0 +1,87 @@
+use quote::ToTokens;
+use syn::{Expr, Lit, Pat};
+use syn_builder::*;
+
+fn tokens(node: impl ToTokens) -> String {
+    node.to_token_stream().to_string()
+}
+
+fn checked(expr: Expr) -> String {
+    assert_roundtrip(&expr);
+    tokens(expr)
+}
+
+#[test]
+fn typed_literals_keep_their_suffix() {
+    assert_eq!(lit_u8(7).to_string(), "7u8");
+    assert_eq!(lit_usize(0).to_string(), "0usize");
+    assert_eq!(checked(lit_i64(3)), "3i64");
+    assert_eq!(checked(lit_f32(1.5)), "1.5f32");
+    assert_eq!(checked(lit_int_unsuffixed(42)), "42");
+    assert_eq!(checked(lit_float_unsuffixed(0.25)), "0.25");
+}
+
+#[test]
+fn negative_numbers_become_unary_exprs() {
+    for expr in [
+        lit_i64(-3),
+        lit_f32(-1.5),
+        lit_int_unsuffixed(-5),
+        lit_float_unsuffixed(-0.5),
+        (-1i8).into_expr(),
+        (i128::MIN + 1).into_expr(),
+    ] {
+        let Expr::Unary(unary) = &expr else {
+            panic!("expected a negation, got `{}`", tokens(&expr));
+        };
+        assert!(matches!(&*unary.expr, Expr::Lit(_)));
+        assert_roundtrip(&expr);
+    }
+    assert_eq!(tokens(lit_i64(-3)), "- 3i64");
+    assert_eq!(
+        tokens(lit_int_unsuffixed(i128::MIN)),
+        "- 170141183460469231731687303715884105728"
+    );
+
+    let positive = 3i32.into_expr();
+    assert!(matches!(positive, Expr::Lit(_)));
+    assert_roundtrip(&positive);
+}
+
+#[test]
+fn into_lit_holds_the_magnitude() {
+    let Lit::Int(int) = (-3i64).into_lit() else {
+        unreachable!()
+    };
+    assert_eq!(int.to_string(), "3i64");
+    let Lit::Float(float) = (-1.5f32).into_lit() else {
+        unreachable!()
+    };
+    assert_eq!(float.to_string(), "1.5f32");
+    assert_eq!(tokens(i8::MIN.into_lit()), "128");
+    assert_roundtrip(&expr_lit(7i32).into_expr());
+}
+
+#[test]
+fn min_and_non_finite_values_become_constants() {
+    assert_eq!(checked(lit_i8(i8::MIN)), "i8 :: MIN");
+    assert_eq!(checked(i64::MIN.into_expr()), "i64 :: MIN");
+    assert_eq!(checked(lit_f32(f32::NAN)), "f32 :: NAN");
+    assert_eq!(checked(f64::INFINITY.into_expr()), "f64 :: INFINITY");
+    assert_eq!(
+        checked(lit_float_unsuffixed(f64::NEG_INFINITY)),
+        "f64 :: NEG_INFINITY"
+    );
+}
+
+#[test]
+fn negative_numbers_become_literal_patterns() {
+    let pat = (-1i32).into_pat();
+    let Pat::Lit(lit) = &pat else {
+        panic!("expected a literal pattern, got `{}`", tokens(&pat));
+    };
+    assert!(matches!(&lit.lit, Lit::Int(int) if int.base10_digits() == "-1"));
+    assert_roundtrip(&arm(pat, expr_tuple::<Expr>([])));
+    assert_roundtrip(&arm(7u8, expr_tuple::<Expr>([])));
+    assert_roundtrip(&arm('c', expr_tuple::<Expr>([])));
+}
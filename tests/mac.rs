@@ -0,0 +1,130 @@
+use quote::{quote, ToTokens};
+use syn::{Expr, Item, Stmt};
+use syn_builder::*;
+
+fn x() -> Expr {
+    expr_path("x").into_expr()
+}
+
+#[test]
+fn comma_separated_args() {
+    let mac = macro_call("matches", [x(), expr_path("Some").into_expr()]);
+
+    assert_eq!(mac.to_token_stream().to_string(), "matches ! (x , Some)");
+    assert_roundtrip(&mac.into_expr());
+}
+
+#[test]
+fn helpers() {
+    let cases = [
+        (vec_macro([1u8, 2u8]), "vec ! [1u8 , 2u8]"),
+        (vec_macro::<Expr>([]), "vec ! []"),
+        (
+            format_macro("{}-{}", [x(), x()]),
+            "format ! (\"{}-{}\" , x , x)",
+        ),
+        (
+            println_macro("done", Vec::<Expr>::new()),
+            "println ! (\"done\")",
+        ),
+        (panic_macro("bad {}", [x()]), "panic ! (\"bad {}\" , x)"),
+        (assert_eq_macro(x(), 1u8), "assert_eq ! (x , 1u8)"),
+        (todo_macro(), "todo ! ()"),
+    ];
+
+    for (mac, expected) in cases {
+        assert_eq!(mac.to_token_stream().to_string(), expected);
+        assert_roundtrip(&mac.into_expr());
+    }
+}
+
+#[test]
+fn statement_and_item_positions() {
+    let stmt = Stmt::Macro(stmt_macro(assert_eq_macro(x(), 1u8)).semi_token(true));
+    assert_eq!(
+        stmt.to_token_stream().to_string(),
+        "assert_eq ! (x , 1u8) ;"
+    );
+    assert_roundtrip(&stmt);
+    assert!(matches!(
+        vec_macro([x()]).into_stmt(),
+        Stmt::Macro(mac) if mac.semi_token.is_none()
+    ));
+
+    let item = macro_call("thread_local", Vec::<Expr>::new()).into_item();
+    assert!(matches!(&item, Item::Macro(mac) if mac.ident.is_none()));
+    assert_roundtrip(&item);
+
+    assert_roundtrip(
+        &expr_block(block([
+            println_macro("{}", [x()]).into_stmt(),
+            format_macro("{}", [x()]).into_stmt(),
+        ]))
+        .into_expr(),
+    );
+}
+
+#[test]
+fn macro_rules_definition() {
+    let rules = macro_rules("square")
+        .rule(macro_fragment("x", "expr"), quote!($x * $x))
+        .rule(
+            [
+                macro_fragment("x", "expr"),
+                quote!(,),
+                macro_repetition_sep(
+                    [macro_fragment("rest", "expr")],
+                    quote!(,),
+                    RepetitionOp::OneOrMore,
+                )
+                .unwrap(),
+            ],
+            quote!(square!($x); $(square!($rest);)*),
+        )
+        .rule(
+            macro_repetition([macro_fragment("t", "tt")], RepetitionOp::ZeroOrMore),
+            x(),
+        )
+        .export(true);
+
+    assert_eq!(
+        rules.to_token_stream().to_string(),
+        "# [macro_export] macro_rules ! square { \
+         ($ x : expr) => { $ x * $ x } ; \
+         ($ x : expr , $ ($ rest : expr) , +) => { square ! ($ x) ; $ (square ! ($ rest) ;) * } ; \
+         ($ ($ t : tt) *) => { x } ; \
+         }"
+    );
+    assert_roundtrip(&rules.clone().into_item());
+
+    assert_eq!(rules.export(false).attrs.len(), 0);
+}
+
+#[test]
+fn macro_repetition_operators_and_separators() {
+    let arrow = macro_repetition_sep(
+        [
+            macro_fragment("k", "ident"),
+            quote!(=>),
+            macro_fragment("v", "expr"),
+        ],
+        quote!(;),
+        RepetitionOp::ZeroOrMore,
+    )
+    .unwrap();
+    assert_eq!(arrow.to_string(), "$ ($ k : ident => $ v : expr) ; *");
+
+    let path =
+        macro_repetition_sep([macro_var("seg")], quote!(::), RepetitionOp::OneOrMore).unwrap();
+    assert_eq!(path.to_string(), "$ ($ seg) :: +");
+
+    let optional = macro_repetition([macro_var("x")], RepetitionOp::ZeroOrOne);
+    assert_eq!(optional.to_string(), "$ ($ x) ?");
+
+    let rules = macro_rules("m").rule([arrow, path, optional], x());
+    assert_roundtrip(&rules.into_item());
+
+    let err =
+        macro_repetition_sep([macro_var("x")], quote!(,), RepetitionOp::ZeroOrOne).unwrap_err();
+    assert_eq!(err.to_string(), "`?` repetitions cannot have a separator");
+}
@@ -0,0 +1,135 @@
+use quote::quote;
+use syn::{Expr, Pat, Stmt};
+use syn_builder::*;
+
+fn roundtrip(pat: impl IntoPat) {
+    assert_roundtrip(&arm(pat, expr_tuple::<Expr>([])));
+}
+
+fn named(name: &str) -> syn::Member {
+    syn::Member::Named(name.into_ident())
+}
+
+#[test]
+fn const_pat() {
+    roundtrip(pat_const(block([expr_path("N").into_expr()])));
+}
+
+#[test]
+fn ident() {
+    roundtrip(pat_ident("x"));
+    roundtrip(pat_ident("x").mutability(true));
+    roundtrip(pat_ident("x").by_ref(true));
+    roundtrip(pat_ident("x").subpat(pat_range(range_limits_closed_variant()).start(1u8).end(9u8)));
+}
+
+#[test]
+fn lit() {
+    roundtrip(pat_lit("text"));
+    roundtrip(pat_lit(3u8));
+    roundtrip(pat_lit(true));
+}
+
+#[test]
+fn macro_pat() {
+    roundtrip(pat_macro(r#macro("pat", quote!(x))));
+}
+
+#[test]
+fn or() {
+    roundtrip(pat_or([pat_lit(1u8), pat_lit(2u8)]));
+}
+
+#[test]
+fn paren() {
+    roundtrip(pat_reference(pat_paren(pat_or([
+        pat_lit(1u8),
+        pat_lit(2u8),
+    ]))));
+}
+
+#[test]
+fn path() {
+    roundtrip(pat_path("Option::None"));
+}
+
+#[test]
+fn range() {
+    roundtrip(pat_range(range_limits_closed_variant()).start(0u8).end(9u8));
+    roundtrip(pat_range(range_limits_half_open_variant()).start(0u8));
+}
+
+#[test]
+fn negative_range() {
+    roundtrip(
+        pat_range(range_limits_closed_variant())
+            .start(-5i32)
+            .end(9i32),
+    );
+    roundtrip(
+        pat_range(range_limits_closed_variant())
+            .start(-9i64)
+            .end(-5i64),
+    );
+    roundtrip(
+        pat_range(range_limits_half_open_variant())
+            .start(-0.5f64)
+            .end(1.5f64),
+    );
+}
+
+#[test]
+fn reference() {
+    roundtrip(pat_reference(pat_ident("x")));
+    roundtrip(pat_reference(pat_ident("x")).mutability(true));
+}
+
+#[test]
+fn slice() {
+    roundtrip(pat_slice([
+        pat_ident("first").into_pat(),
+        pat_rest().into_pat(),
+    ]));
+    roundtrip(pat_slice::<Pat>([]));
+}
+
+#[test]
+fn struct_pat() {
+    roundtrip(pat_struct(
+        "Point",
+        [
+            field_pat(named("x"), pat_ident("a")),
+            field_pat(named("y"), pat_wild()),
+        ],
+    ));
+    roundtrip(
+        pat_struct(
+            "Point",
+            [field_pat(named("x"), pat_ident("x")).colon_token(false)],
+        )
+        .rest(pat_rest()),
+    );
+}
+
+#[test]
+fn tuple() {
+    roundtrip(pat_tuple::<Pat>([]));
+    roundtrip(pat_tuple([pat_ident("a")]));
+    roundtrip(pat_tuple([pat_ident("a"), pat_ident("b")]));
+}
+
+#[test]
+fn tuple_struct() {
+    roundtrip(pat_tuple_struct("Some", [pat_ident("x")]));
+}
+
+#[test]
+fn typed() {
+    assert_roundtrip(&Stmt::Local(local(pat_type(pat_ident("x"), "u8"))));
+    assert_roundtrip(&expr_closure([pat_type(pat_ident("x"), "u8")], expr_path("x")).into_expr());
+}
+
+#[test]
+fn wild() {
+    roundtrip(pat_wild());
+}
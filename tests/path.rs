@@ -0,0 +1,73 @@
+use proc_macro2::Span;
+use syn::{GenericArgument, Lifetime, Path};
+use syn_builder::*;
+
+fn lifetime(name: &str) -> Lifetime {
+    Lifetime::new(name, Span::call_site())
+}
+
+#[test]
+fn segments() {
+    assert_roundtrip(&path([path_segment("std"), path_segment("mem")]));
+    assert_roundtrip(&path([path_segment("core")]).leading_colon(true));
+    assert_roundtrip(&"std::collections::HashMap".into_path());
+}
+
+#[test]
+fn angle_bracketed() {
+    let args: [GenericArgument; 4] = [
+        lifetime("'a").into_generic_argument(),
+        "u8".into_type().into_generic_argument(),
+        assoc_type("Item", "u8").into_generic_argument(),
+        constraint("Err", [trait_bound("Debug")]).into_generic_argument(),
+    ];
+
+    for arg in args.clone() {
+        assert_roundtrip(&arg);
+    }
+
+    assert_roundtrip(&path([
+        path_segment("Foo").arguments(angle_bracketed_generic_arguments(args))
+    ]));
+    assert_roundtrip(&assoc_const("N", 4usize).into_generic_argument());
+}
+
+#[test]
+fn turbofish() {
+    let collect = path([
+        path_segment("Vec")
+            .arguments(angle_bracketed_generic_arguments(["u8".into_type()]).colon2_token(true)),
+        path_segment("new"),
+    ]);
+
+    assert_roundtrip(&expr_path(collect).into_expr());
+}
+
+#[test]
+fn parenthesized() {
+    let bound =
+        |args| syn::TypeParamBound::Trait(trait_bound(path([path_segment("Fn").arguments(args)])));
+
+    assert_roundtrip(&bound(
+        parenthesized_generic_arguments(["u8", "bool"]).output("u8"),
+    ));
+    assert_roundtrip(&bound(parenthesized_generic_arguments(
+        Vec::<syn::Type>::new(),
+    )));
+}
+
+#[test]
+fn qualified() {
+    assert_roundtrip(&type_path("Item").qself(q_self("T", 0usize)).into_type());
+    assert_roundtrip(
+        &type_path("Iterator::Item")
+            .qself(q_self("T", 1usize).as_token(true))
+            .into_type(),
+    );
+}
+
+#[test]
+fn parsed_from_str() {
+    assert!(try_path("a::").is_err());
+    let _: Path = "a::b".into_path();
+}
@@ -0,0 +1,205 @@
+use syn::{Expr, Stmt};
+use syn_builder::*;
+
+fn x(name: &str) -> Expr {
+    expr_path(name).into_expr()
+}
+
+fn s() -> Expr {
+    expr_struct("S", Vec::<syn::FieldValue>::new()).into_expr()
+}
+
+fn binary(left: impl IntoExpr, op: syn::BinOp, right: impl IntoExpr) -> Expr {
+    expr_binary(left, op, right).into_expr()
+}
+
+fn range(start: impl IntoExpr, end: impl IntoExpr) -> Expr {
+    expr_range(range_limits_half_open_variant())
+        .start(start)
+        .end(end)
+        .into_expr()
+}
+
+fn is_paren(expr: &Expr) -> bool {
+    matches!(expr, Expr::Paren(_))
+}
+
+fn operands(expr: &Expr) -> (&Expr, &Expr) {
+    match expr {
+        Expr::Binary(binary) => (&binary.left, &binary.right),
+        Expr::Range(range) => (
+            range.start.as_deref().unwrap(),
+            range.end.as_deref().unwrap(),
+        ),
+        _ => panic!("expected a binary or range expression"),
+    }
+}
+
+fn checked(expr: Expr) -> Expr {
+    let expr = parenthesize(expr);
+    assert_roundtrip(&expr);
+    expr
+}
+
+#[test]
+fn binary_operands() {
+    let expr = checked(binary(
+        binary(x("a"), bin_op_add_variant(), x("b")),
+        bin_op_mul_variant(),
+        x("c"),
+    ));
+    assert!(is_paren(operands(&expr).0));
+
+    let expr = checked(binary(
+        x("a"),
+        bin_op_sub_variant(),
+        binary(x("b"), bin_op_sub_variant(), x("c")),
+    ));
+    assert!(is_paren(operands(&expr).1));
+
+    let expr = checked(binary(
+        binary(x("a"), bin_op_sub_variant(), x("b")),
+        bin_op_sub_variant(),
+        x("c"),
+    ));
+    assert!(!is_paren(operands(&expr).0));
+
+    let expr = checked(binary(
+        binary(x("a"), bin_op_eq_variant(), x("b")),
+        bin_op_eq_variant(),
+        x("c"),
+    ));
+    assert!(is_paren(operands(&expr).0));
+}
+
+#[test]
+fn casts_in_comparisons() {
+    for op in [
+        bin_op_lt_variant(),
+        bin_op_le_variant(),
+        bin_op_shl_variant(),
+    ] {
+        let expr = checked(binary(expr_cast(x("a"), "u8"), op, x("b")));
+        assert!(is_paren(operands(&expr).0));
+    }
+
+    let expr = checked(binary(
+        binary(x("a"), bin_op_add_variant(), expr_cast(x("b"), "u8")),
+        bin_op_lt_variant(),
+        x("c"),
+    ));
+    assert!(is_paren(operands(&expr).0));
+
+    for op in [bin_op_gt_variant(), bin_op_eq_variant()] {
+        let expr = checked(binary(expr_cast(x("a"), "u8"), op, x("b")));
+        assert!(!is_paren(operands(&expr).0));
+    }
+
+    let expr = checked(binary(x("a"), bin_op_lt_variant(), expr_cast(x("b"), "u8")));
+    assert!(!is_paren(operands(&expr).1));
+}
+
+#[test]
+fn struct_literals_in_heads() {
+    let cond = || binary(s(), bin_op_eq_variant(), x("b"));
+
+    let Expr::If(if_) = checked(expr_if(cond(), block([x("a")])).into_expr()) else {
+        unreachable!()
+    };
+    assert!(is_paren(&if_.cond));
+
+    let Expr::If(if_) =
+        checked(expr_if(expr_let(pat_ident("v"), s()), block([x("a")])).into_expr())
+    else {
+        unreachable!()
+    };
+    let Expr::Let(let_) = &*if_.cond else {
+        panic!("`let` condition must stay unparenthesized")
+    };
+    assert!(is_paren(&let_.expr));
+
+    let Expr::While(while_) = checked(
+        expr_while(
+            expr_method_call(s(), "ready", Vec::<Expr>::new()),
+            block([x("a")]),
+        )
+        .into_expr(),
+    ) else {
+        unreachable!()
+    };
+    assert!(is_paren(&while_.cond));
+
+    let Expr::Match(match_) = checked(expr_match(s()).arms([arm(pat_wild(), x("a"))]).into_expr())
+    else {
+        unreachable!()
+    };
+    assert!(is_paren(&match_.expr));
+
+    let Expr::ForLoop(for_loop) = checked(
+        expr_for_loop(
+            pat_ident("v"),
+            expr_method_call(s(), "iter", Vec::<Expr>::new()),
+            block(Vec::<Stmt>::new()),
+        )
+        .into_expr(),
+    ) else {
+        unreachable!()
+    };
+    assert!(is_paren(&for_loop.expr));
+
+    let Expr::If(if_) =
+        checked(expr_if(binary(x("a"), bin_op_eq_variant(), x("b")), block([x("a")])).into_expr())
+    else {
+        unreachable!()
+    };
+    assert!(!is_paren(&if_.cond));
+}
+
+#[test]
+fn ranges() {
+    let expr = checked(range(range(x("a"), x("b")), x("c")));
+    assert!(is_paren(operands(&expr).0));
+
+    let expr = checked(range(x("a"), range(x("b"), x("c"))));
+    assert!(is_paren(operands(&expr).1));
+
+    let expr = checked(range(
+        binary(x("a"), bin_op_or_variant(), x("b")),
+        binary(x("c"), bin_op_add_variant(), x("d")),
+    ));
+    let (start, end) = operands(&expr);
+    assert!(!is_paren(start) && !is_paren(end));
+
+    let expr = checked(binary(range(x("a"), x("b")), bin_op_eq_variant(), x("c")));
+    assert!(is_paren(operands(&expr).0));
+
+    let Expr::MethodCall(call) =
+        checked(expr_method_call(range(x("a"), x("b")), "len", Vec::<Expr>::new()).into_expr())
+    else {
+        unreachable!()
+    };
+    assert!(is_paren(&call.receiver));
+}
+
+#[test]
+fn prefix_and_postfix_operands() {
+    let Expr::Unary(unary) =
+        checked(expr_unary(un_op_neg(), binary(x("a"), bin_op_add_variant(), x("b"))).into_expr())
+    else {
+        unreachable!()
+    };
+    assert!(is_paren(&unary.expr));
+
+    let Expr::Call(call) =
+        checked(expr_call(expr_field(x("a"), index(0usize)), Vec::<Expr>::new()).into_expr())
+    else {
+        unreachable!()
+    };
+    assert!(is_paren(&call.func));
+
+    let Expr::Cast(cast) = checked(expr_cast(expr_unary(un_op_neg(), x("a")), "u8").into_expr())
+    else {
+        unreachable!()
+    };
+    assert!(!is_paren(&cast.expr));
+}
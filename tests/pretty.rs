@@ -0,0 +1,60 @@
+#![cfg(feature = "pretty")]
+
+use std::fs;
+use syn::Expr;
+use syn_builder::*;
+
+fn long_sum() -> Expr {
+    (0..8)
+        .map(|i| expr_path(format!("long_variable_name_{i}").as_str()).into_expr())
+        .reduce(|a, b| expr_binary(a, bin_op_add_variant(), b).into_expr())
+        .unwrap()
+}
+
+#[test]
+fn expr_strips_the_const_wrapper() {
+    assert_eq!(
+        pretty_expr(expr_call(expr_path("f"), [1u8])).to_string(),
+        "f(1u8)\n"
+    );
+
+    let source = pretty_expr(long_sum()).to_string();
+    assert!(source.starts_with("long_variable_name_0 + long_variable_name_1"));
+    assert!(source.contains("\n    + long_variable_name_"));
+    assert!(source.ends_with("long_variable_name_7\n"));
+    assert!(!source.contains("const"));
+}
+
+#[test]
+fn header_comments_each_line() {
+    let pretty = pretty_item(item_const("A", "u8", 1u8));
+
+    assert_eq!(
+        pretty.clone().header("@generated\n\n// keep").to_string(),
+        "// @generated\n//\n// keep\n\nconst A: u8 = 1u8;\n"
+    );
+    assert_eq!(pretty.clone().header("").to_string(), pretty.to_string());
+    assert_eq!(pretty.to_string(), "const A: u8 = 1u8;\n");
+}
+
+#[test]
+fn write_file_skips_unchanged_content() {
+    let dir = std::env::temp_dir().join(format!("syn_builder_pretty_{}", std::process::id()));
+    let _ = fs::remove_dir_all(&dir);
+    let path = dir.join("nested").join("out.rs");
+    let pretty = pretty_item(item_const("A", "u8", 1u8));
+
+    assert!(pretty.write_file(&path).unwrap());
+    let modified = fs::metadata(&path).unwrap().modified().unwrap();
+    std::thread::sleep(std::time::Duration::from_millis(20));
+
+    assert!(!pretty.write_file(&path).unwrap());
+    assert_eq!(fs::metadata(&path).unwrap().modified().unwrap(), modified);
+
+    assert!(pretty.header("changed").write_file(&path).unwrap());
+    assert!(fs::read_to_string(&path)
+        .unwrap()
+        .starts_with("// changed\n"));
+
+    fs::remove_dir_all(dir).unwrap();
+}
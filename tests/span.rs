@@ -0,0 +1,61 @@
+use proc_macro2::{Span, TokenStream, TokenTree};
+use quote::ToTokens;
+use syn_builder::*;
+
+fn located_span() -> Span {
+    let tokens: TokenStream = "\n    located".parse().unwrap();
+    tokens.into_iter().next().unwrap().span()
+}
+
+fn assert_spans(tokens: TokenStream, span: Span) {
+    for tree in tokens {
+        let (start, name) = match &tree {
+            TokenTree::Group(group) => {
+                assert_spans(group.stream(), span);
+                (group.span_open().start(), group.to_string())
+            }
+            tree => (tree.span().start(), tree.to_string()),
+        };
+        assert_eq!(
+            (start.line, start.column),
+            (span.start().line, span.start().column),
+            "`{name}` is not spanned"
+        );
+    }
+}
+
+#[test]
+fn with_span_covers_idents_literals_and_punctuation() {
+    let span = located_span();
+    assert_ne!(span.start().line, 0);
+
+    let item = with_span(span, || {
+        item_fn(
+            signature("f", [arg("a", "u8"), arg("b", "u8")])
+                .generics(generics([type_param("T"), type_param("U")])),
+            block([macro_call("vec", [1u8, 2u8]).into_expr()]),
+        )
+        .into_item()
+    });
+    assert_spans(item.to_token_stream(), span);
+
+    let item = with_span(span, || {
+        item_struct(
+            "S",
+            fields_named([field("u8").ident("x"), field("u16").ident("y")]),
+        )
+        .derive(["Clone", "Debug"])
+        .derive(["Copy"])
+        .into_item()
+    });
+    assert_spans(item.to_token_stream(), span);
+}
+
+#[test]
+fn with_span_restores_previous_span() {
+    let span = located_span();
+    with_span(span, || {
+        assert_eq!(current_span().start().line, span.start().line);
+    });
+    assert_eq!(current_span().start().line, Span::call_site().start().line);
+}
@@ -0,0 +1,42 @@
+use quote::ToTokens;
+use syn::{Block, Stmt};
+use syn_builder::*;
+
+fn tokens(block: Block) -> String {
+    assert_roundtrip(&expr_block(block.clone()).into_expr());
+    block.to_token_stream().to_string()
+}
+
+fn format_x() -> Stmt {
+    format_macro("{}", [expr_path("x")]).into_stmt()
+}
+
+#[test]
+fn tail_macro_stays_an_expression() {
+    assert_eq!(tokens(block([format_x()])), "{ format ! (\"{}\" , x) }");
+    assert_eq!(
+        tokens(block([format_x(), format_x()])),
+        "{ format ! (\"{}\" , x) ; format ! (\"{}\" , x) }"
+    );
+}
+
+#[test]
+fn explicit_macro_semicolons_are_kept() {
+    let stmt = stmt_macro(format_macro("{}", [expr_path("x")])).semi_token(true);
+    assert_eq!(
+        tokens(block([Stmt::Macro(stmt)])),
+        "{ format ! (\"{}\" , x) ; }"
+    );
+}
+
+#[test]
+fn brace_macros_need_no_semicolon() {
+    let mac = syn::Macro {
+        delimiter: macro_delimiter_brace_variant(),
+        ..r#macro("m", proc_macro2::TokenStream::new())
+    };
+    assert_eq!(
+        tokens(block([mac.clone().into_stmt(), mac.into_stmt()])),
+        "{ m ! { } m ! { } }"
+    );
+}
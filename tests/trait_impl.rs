@@ -0,0 +1,117 @@
+mod common;
+
+use common::assert_impl;
+use quote::quote;
+use syn::{parse_quote, ItemTrait};
+use syn_builder::*;
+
+#[test]
+fn stubs_required_items() {
+    let trait_: ItemTrait = parse_quote! {
+        pub trait Store {
+            type Key: Ord;
+            type Error = std::io::Error;
+            const NAME: &'static str;
+            const VERSION: u32 = 1;
+            fn get(&self, key: &Self::Key) -> Option<String>;
+            #[cfg(feature = "write")]
+            fn put(&mut self, key: Self::Key, value: String) -> Result<(), Self::Error>;
+            fn len(&self) -> usize { 0 }
+        }
+    };
+
+    assert_impl(
+        trait_impl(trait_.clone(), "Memory")
+            .assoc_type("Key", "u64")
+            .build()
+            .unwrap(),
+        quote! {
+            impl Store for Memory {
+                type Key = u64;
+                type Error = std::io::Error;
+                const NAME: &'static str = todo!();
+                const VERSION: u32 = 1;
+                fn get(&self, key: &Self::Key) -> Option<String> { todo!() }
+                #[cfg(feature = "write")]
+                fn put(&mut self, key: Self::Key, value: String) -> Result<(), Self::Error> { todo!() }
+            }
+        },
+    );
+
+    let err = trait_impl(trait_, "Memory")
+        .assoc_const("NAME", "memory")
+        .build()
+        .unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "associated type `Key` of `Store` has no default, assign it with `assoc_type`"
+    );
+}
+
+#[test]
+fn unassigned_type_error_points_at_the_type() {
+    let trait_: ItemTrait = syn::parse_str("trait Keyed { type Key; }").unwrap();
+    let err = trait_impl(trait_, "Memory").build().unwrap_err();
+
+    assert_eq!(err.span().start().column, 19);
+}
+
+#[test]
+fn caller_supplied_bodies() {
+    let trait_ = item_trait("Named")
+        .item(trait_item_fn(
+            signature("name", [self_ref()]).output("String"),
+        ))
+        .item(trait_item_fn(signature("id", [self_ref()]).output("u32")));
+
+    let mut calls = Vec::new();
+    let item = trait_impl(trait_, "Thing")
+        .body(|sig| {
+            calls.push(sig.ident.to_string());
+            block([
+                macro_call("unimplemented", [expr_lit(sig.ident.to_string().as_str())]).into_expr(),
+            ])
+        })
+        .build()
+        .unwrap();
+    assert_eq!(calls, ["name", "id"]);
+
+    assert_impl(
+        item,
+        quote! {
+            impl Named for Thing {
+                fn name(&self) -> String { unimplemented!("name") }
+                fn id(&self) -> u32 { unimplemented!("id") }
+            }
+        },
+    );
+}
+
+#[test]
+fn carries_generics_and_where_clause() {
+    let trait_: ItemTrait = parse_quote! {
+        pub unsafe trait Codec<'a, T: Clone = u8, const N: usize = 4>
+        where
+            T: 'a,
+        {
+            type Iter<'b>: Iterator<Item = T> where Self: 'b;
+            fn encode(&self, value: &'a T) -> [u8; N];
+        }
+    };
+
+    assert_impl(
+        trait_impl(trait_, "Wire<T>")
+            .assoc_type("Iter", "std::vec::IntoIter<T>")
+            .build()
+            .unwrap(),
+        quote! {
+            unsafe impl<'a, T: Clone, const N: usize> Codec<'a, T, N> for Wire<T>
+            where
+                T: 'a,
+            {
+                type Iter<'b> = std::vec::IntoIter<T> where Self: 'b;
+                fn encode(&self, value: &'a T) -> [u8; N] { todo!() }
+            }
+        },
+    );
+}
@@ -0,0 +1,109 @@
+use proc_macro2::Span;
+use quote::quote;
+use syn::{Lifetime, Type};
+use syn_builder::*;
+
+fn roundtrip(ty: impl IntoType) {
+    assert_roundtrip(&ty.into_type());
+}
+
+fn lifetime(name: &str) -> Lifetime {
+    Lifetime::new(name, Span::call_site())
+}
+
+#[test]
+fn array() {
+    roundtrip(type_array("u8", 4usize));
+}
+
+#[test]
+fn bare_fn() {
+    roundtrip(type_bare_fn([bare_fn_arg("u8")]));
+    roundtrip(type_bare_fn([bare_fn_arg("u8").name("x")]).output("bool"));
+    roundtrip(
+        type_bare_fn([bare_fn_arg("*const u8")])
+            .unsafety(true)
+            .abi(abi(lit_str("C")))
+            .variadic(bare_variadic()),
+    );
+    roundtrip(
+        type_bare_fn([bare_fn_arg("&'a u8")])
+            .lifetimes(bound_lifetimes([lifetime_param(lifetime("'a"))])),
+    );
+}
+
+#[test]
+fn impl_trait() {
+    roundtrip(type_impl_trait([trait_bound("Iterator<Item = u8>")]));
+    roundtrip(type_impl_trait([
+        trait_bound("Clone").into_type_param_bound(),
+        lifetime("'static").into_type_param_bound(),
+    ]));
+}
+
+#[test]
+fn infer() {
+    roundtrip(type_path("Vec<_>"));
+    roundtrip(type_slice(type_infer()));
+}
+
+#[test]
+fn macro_type() {
+    roundtrip(type_macro(r#macro("ty", quote!(u8))));
+}
+
+#[test]
+fn never() {
+    roundtrip(type_never());
+}
+
+#[test]
+fn paren() {
+    roundtrip(type_reference(type_paren(type_trait_object([
+        trait_bound("Send").into_type_param_bound(),
+        lifetime("'static").into_type_param_bound(),
+    ]))));
+}
+
+#[test]
+fn path() {
+    roundtrip(type_path("std::collections::HashMap<String, Vec<u8>>"));
+    roundtrip(type_path("Iterator::Item").qself(q_self("T", 1usize).as_token(true)));
+}
+
+#[test]
+fn ptr() {
+    roundtrip(type_ptr_const("u8"));
+    roundtrip(type_ptr_mut("u8"));
+}
+
+#[test]
+fn reference() {
+    roundtrip(type_reference("str"));
+    roundtrip(type_reference("str").lifetime(lifetime("'a")));
+    roundtrip(type_reference("u8").mutability(true));
+}
+
+#[test]
+fn slice() {
+    roundtrip(type_slice("u8"));
+}
+
+#[test]
+fn trait_object() {
+    roundtrip(type_trait_object([trait_bound("Fn()")]));
+    roundtrip(type_path("Box<dyn Error + Send>"));
+}
+
+#[test]
+fn tuple() {
+    roundtrip(type_tuple::<Type>([]));
+    roundtrip(type_tuple(["u8"]));
+    roundtrip(type_tuple(["u8", "bool"]));
+}
+
+#[test]
+fn parsed_from_str() {
+    assert!(try_type("Vec<").is_err());
+    roundtrip("&'static [u8]");
+}